@@ -0,0 +1,120 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::{fs, path::Path};
+
+use jsx_dom_expressions::{config::Config, TransformVisitor};
+use swc_core::{
+    common::{
+        comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceMap, GLOBALS,
+    },
+    ecma::{
+        ast::EsVersion,
+        parser::{parse_file_as_module, EsConfig, Syntax},
+        transforms::base::resolver,
+        visit::VisitMutWith,
+    },
+};
+use test::Bencher;
+
+fn config() -> Config {
+    Config {
+        module_name: "r-dom".to_string(),
+        built_ins: vec!["For".to_string(), "Show".to_string()],
+        context_to_custom_elements: true,
+        ..Default::default()
+    }
+}
+
+/// Generates a module shaped like the icon packs produced by svg-to-jsx tooling:
+/// one exported component per icon, each a static `<svg>` tree with a few
+/// spread and dynamic attributes.
+fn icon_module(icons: usize) -> String {
+    let mut source = String::new();
+    for i in 0..icons {
+        source.push_str(&format!(
+            r#"export const Icon{i} = props => (
+  <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" {{...props}} class={{props.class}}>
+    <title>{{props.title ?? "Icon {i}"}}</title>
+    <path d="M{i} 2L2 7l10 5 10-5-10-5z" />
+    <path d="M2 17l10 5 10-5" />
+    <path d="M2 12l10 5 10-5" />
+    <circle cx="12" cy="12" r={{props.size / 2}} />
+  </svg>
+);
+"#
+        ));
+    }
+    source
+}
+
+/// Concatenates every fixture input into a single module so the benchmark
+/// exercises the full range of element, component and expression handling.
+fn fixtures_module() -> String {
+    let mut inputs = Vec::new();
+    collect_inputs(Path::new("tests/fixture"), &mut inputs);
+    inputs.sort();
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(index, path)| {
+            format!(
+                "function fixture{index}() {{\n{}\n}}\n",
+                fs::read_to_string(path)
+                    .unwrap()
+                    .replace("import ", "// import ")
+            )
+        })
+        .collect()
+}
+
+fn collect_inputs(dir: &Path, inputs: &mut Vec<std::path::PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_inputs(&path, inputs);
+        } else if path.file_name().map_or(false, |name| name == "code.js") {
+            inputs.push(path);
+        }
+    }
+}
+
+fn transform(source: &str) {
+    GLOBALS.set(&Globals::new(), || {
+        let cm: Lrc<SourceMap> = Default::default();
+        let comments = SingleThreadedComments::default();
+        let fm = cm.new_source_file(FileName::Anon, source.to_string());
+        let mut module = parse_file_as_module(
+            &fm,
+            Syntax::Es(EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            EsVersion::latest(),
+            Some(&comments),
+            &mut vec![],
+        )
+        .unwrap();
+        let unresolved_mark = Mark::new();
+        module.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), false));
+        module.visit_mut_with(&mut TransformVisitor::new(
+            config(),
+            comments,
+            unresolved_mark,
+        ));
+        test::black_box(module);
+    });
+}
+
+#[bench]
+fn bench_icon_module(b: &mut Bencher) {
+    let source = icon_module(500);
+    b.iter(|| transform(&source));
+}
+
+#[bench]
+fn bench_fixtures(b: &mut Bencher) {
+    let source = fixtures_module();
+    b.iter(|| transform(&source));
+}
@@ -1,30 +1,329 @@
+use std::collections::HashMap;
+
+use schemars::{schema_for, JsonSchema};
 use serde::{Deserialize, Serialize};
+use swc_core::ecma::ast::Ident;
 
-#[derive(Serialize, Deserialize)]
+/// Options of the transform.
+#[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct Config {
+    /// The module runtime helpers are imported from.
     pub module_name: String,
-    pub generate: String,
+    pub generate: Generate,
+    /// Compile for hydration of server rendered markup.
     pub hydratable: bool,
+    /// Delegate common events to a single listener instead of attaching one
+    /// per element.
     pub delegate_events: bool,
+    /// Events delegated in addition to the built-in list.
     pub delegated_events: Vec<String>,
+    /// Components imported from `moduleName` when used without a binding in
+    /// scope, such as `For` or `Show`.
     pub built_ins: Vec<String>,
+    /// Only compile files with a `@jsxImportSource` comment.
     pub require_import_source: bool,
+    /// Memoize the condition of ternaries and `&&` expressions in JSX.
     pub wrap_conditionals: bool,
+    /// Leave out closing tags that the HTML parser infers from the template.
     pub omit_nested_closing_tags: bool,
+    /// Pass the owner context to custom elements.
     pub context_to_custom_elements: bool,
+    /// Comment marking an expression as static, so it isn't wrapped in an
+    /// effect.
     pub static_marker: String,
+    /// Runtime helper used to wrap dynamic expressions.
     pub effect_wrapper: String,
+    /// Runtime helper used to memoize conditions.
     pub memo_wrapper: String,
+    /// Check that templates form valid HTML.
     pub validate: bool,
+    pub whitespace: Whitespace,
+    /// Only declare variables for template nodes that are used and reach each
+    /// one through the shortest `firstChild`/`lastChild` walk.
+    pub optimize_walks: bool,
+    /// Create each template on its first instantiation instead of when the
+    /// module is evaluated.
+    pub lazy_templates: bool,
+    /// Accept React-only attributes such as `dangerouslySetInnerHTML` or
+    /// `defaultValue`, converting them with a warning.
+    pub react_compat: bool,
+    /// Name of a template tag, such as `html`, whose tagged templates are
+    /// compiled like the equivalent JSX. Empty disables it.
+    pub html_tag: String,
+    /// A Custom Elements Manifest (`custom-elements.json`), or the subset of
+    /// it describing the elements used, to decide how their bindings compile.
+    pub custom_elements_manifest: Option<CustomElementsManifest>,
+    /// Import relative asset URLs found in static attributes so bundlers
+    /// process the files, assigning the imported URL instead.
+    pub transform_asset_urls: bool,
+    /// The attributes holding asset URLs, by tag name.
+    pub asset_url_attributes: HashMap<String, Vec<String>>,
+    /// Compile `.map` calls with a JSX callback in children positions to
+    /// `<For>`, so list rows are reused instead of recreated on every change.
+    pub map_children_to_for: bool,
+    /// Rewrite destructured props of components into `props.x` reads so they
+    /// stay reactive, using `mergeProps` for defaults and `splitProps` for
+    /// rest props, both imported from the package of `moduleName`.
+    pub transform_destructured_props: bool,
+    /// Move inline event handlers that only use module-level or global
+    /// bindings to module-level constants, so they aren't recreated for every
+    /// element.
+    pub hoist_event_handlers: bool,
+    /// Where delegated events are listened to, as an identifier or a member
+    /// path such as `window.appRoot`, for apps rendered inside shadow roots
+    /// or iframes. Empty uses the runtime's default, the document.
+    pub delegation_target: String,
+    /// When set, the module exports its delegated event names under this
+    /// name instead of calling `delegateEvents`, leaving delegation to the
+    /// app. Modules without delegated events export an empty list.
+    pub export_delegated_events: String,
+}
+
+/// The kind of output to compile to.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Generate {
+    /// Templates cloned and updated in the browser.
+    #[default]
+    Dom,
+    /// Strings rendered on the server.
+    Ssr,
+    /// Calls to a custom renderer's `createElement` and `insert`.
+    Universal,
+}
+
+/// How whitespace in JSX text is turned into template text.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Whitespace {
+    /// React style: lines are trimmed and whitespace-only lines dropped.
+    #[default]
+    Jsx,
+    /// Text is kept exactly as written.
+    Preserve,
+    /// Runs of whitespace collapse to a single space, like HTML rendering.
+    CollapseHtml,
+}
+
+/// The parts of the Custom Elements Manifest schema the compiler reads.
+/// Everything else in the file is ignored.
+#[derive(Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CustomElementsManifest {
+    pub modules: Vec<ManifestModule>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ManifestModule {
+    pub declarations: Vec<ManifestDeclaration>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ManifestDeclaration {
+    pub tag_name: Option<String>,
+    pub members: Vec<ManifestMember>,
+    pub attributes: Vec<ManifestAttribute>,
+    pub events: Vec<ManifestEvent>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ManifestMember {
+    pub kind: String,
+    pub name: String,
+    #[serde(rename = "static")]
+    pub is_static: bool,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ManifestAttribute {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ManifestEvent {
+    pub name: String,
+}
+
+/// Tag of the block comments overriding options for a single file, as in
+/// `/** @jsxDomExpressions generate=ssr delegateEvents=false */`.
+pub const PRAGMA: &str = "@jsxDomExpressions";
+
+impl Config {
+    /// Parses the plugin options, rejecting unknown options, values of the
+    /// wrong type and combinations that can't work together.
+    pub fn from_json(json: &str) -> Result<Config, String> {
+        let value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if let Some(options) = value.as_object() {
+            let known = Config::option_names();
+            if let Some(key) = options.keys().find(|key| !known.contains(key)) {
+                return Err(unknown_option(key, &known));
+            }
+        }
+        let config: Config = serde_json::from_value(value).map_err(|e| e.to_string())?;
+        config.check()?;
+        Ok(config)
+    }
+
+    /// The JSON schema of the options, as published in `config.schema.json`.
+    pub fn json_schema() -> String {
+        let mut schema = schema_for!(Config);
+        schema.schema.metadata().title = Some("jsx-dom-expressions options".to_owned());
+        serde_json::to_string_pretty(&schema).unwrap() + "\n"
+    }
+
+    /// The names of all options, as written in the configuration.
+    pub fn option_names() -> Vec<String> {
+        match serde_json::to_value(Config::default()) {
+            Ok(serde_json::Value::Object(options)) => options.keys().cloned().collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Rejects combinations of options that contradict each other.
+    pub fn check(&self) -> Result<(), String> {
+        if self.hydratable && self.generate == Generate::Universal {
+            return Err("`hydratable` is not supported with `generate: \"universal\"`".to_owned());
+        }
+        if !self.delegate_events
+            && (!self.delegation_target.is_empty() || !self.export_delegated_events.is_empty())
+        {
+            return Err(
+                "`delegationTarget` and `exportDelegatedEvents` require `delegateEvents`"
+                    .to_owned(),
+            );
+        }
+        if !self.delegation_target.is_empty() && !self.export_delegated_events.is_empty() {
+            return Err(
+                "`delegationTarget` has no effect when `exportDelegatedEvents` is set".to_owned(),
+            );
+        }
+        if !self.delegation_target.is_empty()
+            && self
+                .delegation_target
+                .split('.')
+                .any(|segment| Ident::verify_symbol(segment.trim()).is_err())
+        {
+            return Err(format!(
+                "`delegationTarget` must be an identifier or a member path, found `{}`",
+                self.delegation_target
+            ));
+        }
+        if !self.export_delegated_events.is_empty()
+            && Ident::verify_symbol(&self.export_delegated_events).is_err()
+        {
+            return Err(format!(
+                "`exportDelegatedEvents` must be an identifier, found `{}`",
+                self.export_delegated_events
+            ));
+        }
+        Ok(())
+    }
+
+    /// Applies the `key=value` options following [PRAGMA] in `comment` over
+    /// the current ones. Values are read as JSON, falling back to plain
+    /// strings. Returns a message for every option that was not applied.
+    pub fn apply_pragma(&mut self, comment: &str) -> Vec<String> {
+        let Some((_, options)) = comment.split_once(PRAGMA) else {
+            return vec![];
+        };
+        let mut warnings = vec![];
+        let known = Config::option_names();
+        let original = serde_json::to_value(&*self).unwrap();
+        let mut config = original.clone();
+        for option in options
+            .split_whitespace()
+            .filter(|option| !matches!(*option, "*" | PRAGMA))
+        {
+            let Some((key, value)) = option.split_once('=') else {
+                warnings.push(format!(
+                    "{}: expected `key=value`, found `{}`",
+                    PRAGMA, option
+                ));
+                continue;
+            };
+            if !known.iter().any(|name| name == key) {
+                warnings.push(format!("{}: {}", PRAGMA, unknown_option(key, &known)));
+                continue;
+            }
+            let previous = config[key].clone();
+            config[key] = serde_json::from_str(value)
+                .unwrap_or_else(|_| serde_json::Value::String(value.to_owned()));
+            if let Err(error) = serde_json::from_value::<Config>(config.clone()) {
+                warnings.push(format!(
+                    "{}: invalid value `{}` for `{}`, {}",
+                    PRAGMA, value, key, error
+                ));
+                config[key] = previous;
+            }
+        }
+        let config: Config = serde_json::from_value(config).unwrap();
+        match config.check() {
+            Ok(()) => *self = config,
+            Err(error) => {
+                warnings.push(format!("{}: {}, the options were ignored", PRAGMA, error));
+                *self = serde_json::from_value(original).unwrap();
+            }
+        }
+        warnings
+    }
+}
+
+/// Reports `key` as unknown, suggesting the closest option name when there is
+/// a plausible one.
+fn unknown_option(key: &str, known: &[String]) -> String {
+    let suggestion = known
+        .iter()
+        .map(|name| {
+            (
+                edit_distance(&key.to_lowercase(), &name.to_lowercase()),
+                name,
+            )
+        })
+        .min()
+        .filter(|(distance, _)| *distance <= 2.max(key.len() / 3));
+    match suggestion {
+        Some((_, name)) => format!("Unknown option `{}`, did you mean `{}`?", key, name),
+        None => format!("Unknown option `{}`", key),
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(previous + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl CustomElementsManifest {
+    /// Finds the declaration of the custom element registered as `tag_name`.
+    pub fn element(&self, tag_name: &str) -> Option<&ManifestDeclaration> {
+        self.modules
+            .iter()
+            .flat_map(|module| &module.declarations)
+            .find(|declaration| declaration.tag_name.as_deref() == Some(tag_name))
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
             module_name: "solid-js/web".to_owned(),
-            generate: "dom".to_owned(),
+            generate: Generate::Dom,
             hydratable: false,
             delegate_events: true,
             delegated_events: vec![],
@@ -37,6 +336,35 @@ impl Default for Config {
             effect_wrapper: "effect".to_owned(),
             memo_wrapper: "memo".to_owned(),
             validate: true,
+            whitespace: Whitespace::Jsx,
+            optimize_walks: false,
+            lazy_templates: false,
+            react_compat: false,
+            html_tag: "".to_owned(),
+            custom_elements_manifest: None,
+            transform_asset_urls: false,
+            map_children_to_for: false,
+            transform_destructured_props: false,
+            hoist_event_handlers: false,
+            delegation_target: "".to_owned(),
+            export_delegated_events: "".to_owned(),
+            asset_url_attributes: [
+                ("img", vec!["src", "srcset"]),
+                ("source", vec!["src", "srcset"]),
+                ("video", vec!["src", "poster"]),
+                ("audio", vec!["src"]),
+                ("track", vec!["src"]),
+                ("image", vec!["href", "xlink:href"]),
+                ("use", vec!["href", "xlink:href"]),
+            ]
+            .into_iter()
+            .map(|(tag, attributes)| {
+                (
+                    tag.to_owned(),
+                    attributes.into_iter().map(str::to_owned).collect(),
+                )
+            })
+            .collect(),
         }
     }
 }
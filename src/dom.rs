@@ -1,3 +1,10 @@
+pub mod asset_urls;
+pub mod class_names;
 pub mod constants;
+pub mod custom_elements;
 pub mod element;
+pub mod event_handlers;
+pub mod react_compat;
+pub mod scoped_style;
 pub mod template;
+pub mod walk;
@@ -0,0 +1,162 @@
+use crate::TransformVisitor;
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{ast::*, utils::prepend_stmt},
+};
+
+fn is_relative(url: &str) -> bool {
+    url.starts_with("./") || url.starts_with("../")
+}
+
+fn attr_name(name: &JSXAttrName) -> String {
+    match name {
+        JSXAttrName::Ident(id) => id.sym.to_string(),
+        JSXAttrName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+    }
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Whether `attr` holds an asset URL that will be turned into an import,
+    /// which makes the element need a reference.
+    pub fn is_asset_url_attr(&self, attr: &JSXAttr, tag_name: &str) -> bool {
+        let Some(JSXAttrValue::Lit(Lit::Str(value))) = &attr.value else {
+            return false;
+        };
+        self.config.transform_asset_urls
+            && self
+                .config
+                .asset_url_attributes
+                .get(tag_name)
+                .is_some_and(|names| names.contains(&attr_name(&attr.name)))
+            && value.value.split(',').any(|url| is_relative(url.trim()))
+    }
+
+    /// Replaces relative URLs in the static asset attributes of `tag_name`
+    /// with the bindings of hoisted imports, leaving other values untouched.
+    pub fn process_asset_urls(
+        &mut self,
+        attributes: Vec<JSXAttrOrSpread>,
+        tag_name: &str,
+    ) -> Vec<JSXAttrOrSpread> {
+        let Some(names) = self.config.asset_url_attributes.get(tag_name).cloned() else {
+            return attributes;
+        };
+        attributes
+            .into_iter()
+            .map(|attribute| {
+                let JSXAttrOrSpread::JSXAttr(mut attr) = attribute else {
+                    return attribute;
+                };
+                let name = attr_name(&attr.name);
+                if let Some(JSXAttrValue::Lit(Lit::Str(value))) = &attr.value {
+                    if names.contains(&name) {
+                        if let Some(expr) = self.asset_url_value(&value.value, name == "srcset") {
+                            attr.value = Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                span: DUMMY_SP,
+                                expr: JSXExpr::Expr(Box::new(expr)),
+                            }));
+                        }
+                    }
+                }
+                JSXAttrOrSpread::JSXAttr(attr)
+            })
+            .collect()
+    }
+
+    /// `./logo.png` becomes the import binding itself. A `srcset` or a URL
+    /// with a `#fragment` becomes a template literal around the bindings.
+    fn asset_url_value(&mut self, value: &str, is_srcset: bool) -> Option<Expr> {
+        let urls: Vec<&str> = if is_srcset {
+            value.split(',').map(str::trim).collect()
+        } else {
+            vec![value]
+        };
+        if !urls.iter().any(|url| is_relative(url)) {
+            return None;
+        }
+
+        let mut quasis = vec![String::new()];
+        let mut exprs = vec![];
+        for (i, candidate) in urls.into_iter().enumerate() {
+            if i > 0 {
+                quasis.last_mut().unwrap().push_str(", ");
+            }
+            let (url, descriptor) = candidate
+                .split_once(char::is_whitespace)
+                .map_or((candidate, ""), |(url, descriptor)| (url, descriptor));
+            if !is_relative(url) {
+                quasis.last_mut().unwrap().push_str(candidate);
+                continue;
+            }
+            let (path, fragment) = url
+                .split_once('#')
+                .map_or((url, None), |(path, fragment)| (path, Some(fragment)));
+            exprs.push(Box::new(Expr::Ident(self.register_asset_import(path))));
+            let mut rest = String::new();
+            if let Some(fragment) = fragment {
+                rest += &format!("#{}", fragment);
+            }
+            if !descriptor.is_empty() {
+                rest += &format!(" {}", descriptor.trim());
+            }
+            quasis.push(rest);
+        }
+
+        if exprs.len() == 1 && quasis.iter().all(String::is_empty) {
+            return Some(*exprs.remove(0));
+        }
+        let count = quasis.len();
+        Some(Expr::Tpl(Tpl {
+            span: DUMMY_SP,
+            exprs,
+            quasis: quasis
+                .into_iter()
+                .enumerate()
+                .map(|(i, text)| TplElement {
+                    span: DUMMY_SP,
+                    tail: i + 1 == count,
+                    raw: text
+                        .replace('\\', "\\\\")
+                        .replace('`', "\\`")
+                        .replace("${", "\\${")
+                        .into(),
+                    cooked: Some(text.into()),
+                })
+                .collect(),
+        }))
+    }
+
+    fn register_asset_import(&mut self, url: &str) -> Ident {
+        if let Some((_, id)) = self.asset_imports.iter().find(|(path, _)| path == url) {
+            return id.clone();
+        }
+        let id = self.generate_uid_identifier("asset$");
+        self.asset_imports.push((url.to_string(), id.clone()));
+        id
+    }
+
+    pub fn insert_asset_imports(&mut self, module: &mut Module) {
+        for (url, id) in std::mem::take(&mut self.asset_imports).into_iter().rev() {
+            prepend_stmt(
+                &mut module.body,
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                        span: DUMMY_SP,
+                        local: id,
+                    })],
+                    src: Box::new(Str {
+                        span: DUMMY_SP,
+                        value: url.into(),
+                        raw: None,
+                    }),
+                    span: DUMMY_SP,
+                    type_only: false,
+                    with: None,
+                })),
+            );
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{shared::evaluate::is_truthy, shared::utils::lit_to_string, TransformVisitor};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{ast::*, utils::quote_ident},
+};
+
+/// The pieces a clsx-style `class` value compiles to.
+#[derive(Default)]
+struct ClassParts {
+    /// Names that are always present, folded into the template.
+    names: Vec<String>,
+    /// Names toggled by a condition, compiled to `class:name`.
+    toggles: Vec<(String, Expr)>,
+    /// Object entries with computed keys or spreads and the entries only
+    /// known at runtime, left to `classList`.
+    objects: Vec<PropOrSpread>,
+}
+
+fn is_class_name_attr(attr: &JSXAttr) -> bool {
+    matches!(&attr.name, JSXAttrName::Ident(name) if &name.sym == "class" || &name.sym == "className")
+}
+
+fn class_value(attr: &JSXAttr) -> Option<&Expr> {
+    match &attr.value {
+        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+        })) if matches!(**expr, Expr::Array(_) | Expr::Object(_)) => Some(expr),
+        _ => None,
+    }
+}
+
+/// Negates `expr`, flipping comparisons and removing a leading `!` instead
+/// of stacking another one.
+fn not(expr: Expr) -> Expr {
+    match expr {
+        Expr::Unary(UnaryExpr {
+            op: UnaryOp::Bang,
+            arg,
+            ..
+        }) => *arg,
+        Expr::Paren(ParenExpr { expr, .. }) => not(*expr),
+        Expr::Bin(
+            bin @ BinExpr {
+                op: BinaryOp::EqEqEq | BinaryOp::NotEqEq | BinaryOp::EqEq | BinaryOp::NotEq,
+                ..
+            },
+        ) => Expr::Bin(BinExpr {
+            op: match bin.op {
+                BinaryOp::EqEqEq => BinaryOp::NotEqEq,
+                BinaryOp::NotEqEq => BinaryOp::EqEqEq,
+                BinaryOp::EqEq => BinaryOp::NotEq,
+                _ => BinaryOp::EqEq,
+            },
+            ..bin
+        }),
+        expr => Expr::Unary(UnaryExpr {
+            span: DUMMY_SP,
+            op: UnaryOp::Bang,
+            arg: Box::new(expr),
+        }),
+    }
+}
+
+/// A `classList` entry adding the classes of `names`, a string only known at
+/// runtime. Falsy values become `""`, which `classList` skips, so they add
+/// nothing as with `clsx`.
+fn runtime_names(names: Expr) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Computed(ComputedPropName {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: BinaryOp::LogicalOr,
+                left: Box::new(names),
+                right: Box::new(Expr::Lit(Lit::Str("".into()))),
+            })),
+        }),
+        value: Box::new(Expr::Lit(Lit::Bool(true.into()))),
+    })))
+}
+
+fn expr_attr(name: JSXAttrName, expr: Expr) -> JSXAttrOrSpread {
+    JSXAttrOrSpread::JSXAttr(JSXAttr {
+        span: DUMMY_SP,
+        name,
+        value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(expr)),
+        })),
+    })
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Expands array and object `class` values the way `clsx` would resolve
+    /// them: constant entries become static classes and conditional ones
+    /// `class:` toggles. The remaining entries are only known at runtime and
+    /// go to `classList`, as strings of class names.
+    pub fn process_class_names(
+        &mut self,
+        attributes: Vec<JSXAttrOrSpread>,
+    ) -> Vec<JSXAttrOrSpread> {
+        let mut result = vec![];
+        for attribute in attributes {
+            let parts = match &attribute {
+                JSXAttrOrSpread::JSXAttr(attr) if is_class_name_attr(attr) => class_value(attr)
+                    .map(|value| {
+                        let mut parts = ClassParts::default();
+                        self.collect_class_parts(value, &mut parts);
+                        parts
+                    }),
+                _ => None,
+            };
+            let Some(parts) = parts else {
+                result.push(attribute);
+                continue;
+            };
+            if !parts.names.is_empty() {
+                result.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    span: DUMMY_SP,
+                    name: JSXAttrName::Ident(quote_ident!("class")),
+                    value: Some(JSXAttrValue::Lit(Lit::Str(parts.names.join(" ").into()))),
+                }));
+            }
+            if !parts.objects.is_empty() {
+                result.push(expr_attr(
+                    JSXAttrName::Ident(quote_ident!("classList")),
+                    Expr::Object(ObjectLit {
+                        span: DUMMY_SP,
+                        props: parts.objects,
+                    }),
+                ));
+            }
+            for (name, condition) in parts.toggles {
+                result.push(expr_attr(
+                    JSXAttrName::JSXNamespacedName(JSXNamespacedName {
+                        ns: quote_ident!("class"),
+                        name: quote_ident!(name),
+                    }),
+                    condition,
+                ));
+            }
+        }
+        result
+    }
+
+    fn collect_class_parts(&self, expr: &Expr, parts: &mut ClassParts) {
+        if let Some(lit) = self.evaluate(expr) {
+            if is_truthy(&lit) && !matches!(lit, Lit::Bool(_)) {
+                parts
+                    .names
+                    .extend(lit_to_string(&lit).split_whitespace().map(str::to_string));
+            }
+            return;
+        }
+        match expr {
+            Expr::Paren(ParenExpr { expr, .. }) => self.collect_class_parts(expr, parts),
+            Expr::Array(ArrayLit { elems, .. }) => {
+                for elem in elems.iter().flatten() {
+                    if elem.spread.is_none() {
+                        self.collect_class_parts(&elem.expr, parts);
+                        continue;
+                    }
+                    // `...list` adds `list.filter(Boolean).join(" ")`
+                    let call = |obj: Expr, method: &str, arg: Expr| {
+                        Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                                span: DUMMY_SP,
+                                obj: Box::new(obj),
+                                prop: MemberProp::Ident(quote_ident!(method)),
+                            }))),
+                            args: vec![arg.into()],
+                            type_args: None,
+                        })
+                    };
+                    let truthy = call(
+                        (*elem.expr).clone(),
+                        "filter",
+                        Expr::Ident(quote_ident!("Boolean")),
+                    );
+                    parts.objects.push(runtime_names(call(
+                        truthy,
+                        "join",
+                        Expr::Lit(Lit::Str(" ".into())),
+                    )));
+                }
+            }
+            Expr::Object(ObjectLit { props, .. }) => {
+                for prop in props {
+                    let key_value = match prop {
+                        PropOrSpread::Prop(prop) => match &**prop {
+                            Prop::KeyValue(KeyValueProp {
+                                key: PropName::Ident(key),
+                                value,
+                            }) => Some((key.sym.to_string(), (**value).clone())),
+                            Prop::KeyValue(KeyValueProp {
+                                key: PropName::Str(key),
+                                value,
+                            }) => Some((key.value.to_string(), (**value).clone())),
+                            Prop::Shorthand(id) => {
+                                Some((id.sym.to_string(), Expr::Ident(id.clone())))
+                            }
+                            _ => None,
+                        },
+                        PropOrSpread::Spread(_) => None,
+                    };
+                    let Some((key, value)) = key_value else {
+                        parts.objects.push(prop.clone());
+                        continue;
+                    };
+                    self.collect_conditional_names(&key, value, parts);
+                }
+            }
+            Expr::Bin(BinExpr {
+                op: BinaryOp::LogicalAnd,
+                left,
+                right,
+                ..
+            }) if self.evaluate(right).is_some() => {
+                let lit = self.evaluate(right).unwrap();
+                if is_truthy(&lit) {
+                    self.collect_conditional_names(&lit_to_string(&lit), (**left).clone(), parts);
+                }
+            }
+            Expr::Cond(CondExpr {
+                test, cons, alt, ..
+            }) if self.evaluate(cons).is_some() && self.evaluate(alt).is_some() => {
+                for (branch, condition) in [(cons, (**test).clone()), (alt, not((**test).clone()))]
+                {
+                    let lit = self.evaluate(branch).unwrap();
+                    if is_truthy(&lit) {
+                        self.collect_conditional_names(&lit_to_string(&lit), condition, parts);
+                    }
+                }
+            }
+            _ => parts.objects.push(runtime_names(expr.clone())),
+        }
+    }
+
+    /// Adds each class of `names` depending on `condition`, statically when
+    /// the condition is a constant.
+    fn collect_conditional_names(&self, names: &str, condition: Expr, parts: &mut ClassParts) {
+        match self.evaluate(&condition) {
+            Some(lit) if !is_truthy(&lit) => {}
+            Some(_) => parts
+                .names
+                .extend(names.split_whitespace().map(str::to_string)),
+            None => parts.toggles.extend(
+                names
+                    .split_whitespace()
+                    .map(|name| (name.to_string(), condition.clone())),
+            ),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{config::ManifestDeclaration, shared::utils::emit_warning, TransformVisitor};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{ast::*, utils::quote_ident},
+};
+
+/// Attributes handled by the compiler itself or valid on every element.
+const UNCHECKED_ATTRIBUTES: [&str; 21] = [
+    "ref",
+    "children",
+    "class",
+    "className",
+    "classList",
+    "style",
+    "textContent",
+    "innerHTML",
+    "innerText",
+    "id",
+    "slot",
+    "part",
+    "exportparts",
+    "title",
+    "hidden",
+    "inert",
+    "tabindex",
+    "tabIndex",
+    "lang",
+    "dir",
+    "role",
+];
+
+fn namespaced(ns: &str, name: &str) -> JSXAttrName {
+    JSXAttrName::JSXNamespacedName(JSXNamespacedName {
+        ns: quote_ident!(ns),
+        name: quote_ident!(name),
+    })
+}
+
+/// `ValueChanged` written after `on` matches a `value-changed` event.
+fn kebab_case(name: &str) -> String {
+    let mut kebab = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                kebab.push('-');
+            }
+            kebab.push(c.to_ascii_lowercase());
+        } else {
+            kebab.push(c);
+        }
+    }
+    kebab
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Rewrites the attributes of a custom element declared in the manifest to
+    /// explicit `prop:`, `attr:` and `on:` bindings, warning about names the
+    /// element doesn't declare.
+    pub fn process_custom_element(
+        &self,
+        attributes: Vec<JSXAttrOrSpread>,
+        tag_name: &str,
+    ) -> Vec<JSXAttrOrSpread> {
+        let Some(declaration) = self
+            .config
+            .custom_elements_manifest
+            .as_ref()
+            .and_then(|manifest| manifest.element(tag_name))
+        else {
+            return attributes;
+        };
+
+        attributes
+            .into_iter()
+            .map(|attribute| match attribute {
+                JSXAttrOrSpread::JSXAttr(attr) => {
+                    JSXAttrOrSpread::JSXAttr(custom_element_attr(attr, declaration, tag_name))
+                }
+                _ => attribute,
+            })
+            .collect()
+    }
+}
+
+fn custom_element_attr(
+    mut attr: JSXAttr,
+    declaration: &ManifestDeclaration,
+    tag_name: &str,
+) -> JSXAttr {
+    let JSXAttrName::Ident(id) = &attr.name else {
+        return attr;
+    };
+    let name = id.sym.to_string();
+    if UNCHECKED_ATTRIBUTES.contains(&name.as_str())
+        || name.starts_with("aria-")
+        || name.starts_with("data-")
+    {
+        return attr;
+    }
+
+    if let Some(event) = name.strip_prefix("on").filter(|event| !event.is_empty()) {
+        let kebab = kebab_case(event);
+        // Undeclared events are left alone, they may be native DOM events.
+        if let Some(declared) = declaration.events.iter().find(|declared| {
+            declared.name == event
+                || declared.name.eq_ignore_ascii_case(event)
+                || declared.name == kebab
+        }) {
+            attr.name = namespaced("on", &declared.name);
+        }
+        return attr;
+    }
+
+    let is_literal = matches!(attr.value, None | Some(JSXAttrValue::Lit(_)));
+    let is_property = declaration
+        .members
+        .iter()
+        .any(|member| member.kind == "field" && !member.is_static && member.name == name);
+    let attribute = declaration
+        .attributes
+        .iter()
+        .find(|attribute| attribute.name.eq_ignore_ascii_case(&name));
+
+    match attribute {
+        // Literal values of declared attributes stay in the template.
+        Some(_) if is_literal => {}
+        Some(attribute) if !is_property => attr.name = namespaced("attr", &attribute.name),
+        _ if is_property => {
+            attr.name = namespaced("prop", &name);
+            if attr.value.is_none() {
+                attr.value = Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    span: DUMMY_SP,
+                    expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Bool(true.into())))),
+                }));
+            }
+        }
+        _ => emit_warning(
+            attr.span,
+            &format!("`{}` is not a declared member of `<{}>`", name, tag_name),
+        ),
+    }
+    attr
+}
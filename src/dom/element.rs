@@ -1,27 +1,32 @@
 use crate::{
+    config::Whitespace,
     shared::{
         constants::{
             get_prop_alias, ALIASES, CHILD_PROPERTIES, DELEGATED_EVENTS, PROPERTIES, SVGNAMESPACE,
             SVG_ELEMENTS, VOID_ELEMENTS,
         },
+        evaluate::is_truthy,
         structs::{DynamicAttr, ProcessSpreadsInfo, TemplateInstantiation},
-        transform::{is_component, TransformInfo},
+        transform::{is_component, ConstInit, TransformInfo},
         utils::{
-            can_native_spread, check_length, convert_jsx_identifier, escape_backticks, escape_html,
-            filter_children, get_tag_name, is_l_val, is_static_expr, lit_to_string,
+            can_native_spread, check_length, convert_jsx_identifier, emit_error, escape_backticks,
+            escape_html, flatten_children, get_tag_name, is_l_val, is_static_expr, lit_to_string,
             to_property_name, trim_whitespace, wrapped_by_text, RESERVED_NAME_SPACES,
         },
     },
     TransformVisitor,
 };
+use once_cell::sync::Lazy;
 use regex::Regex;
 use swc_core::{
     common::{comments::Comments, DUMMY_SP},
     ecma::ast::*,
-    ecma::{minifier::eval::EvalResult, utils::quote_ident},
+    ecma::utils::{private_ident, quote_ident},
 };
 
-use super::constants::{BLOCK_ELEMENTS, INLINE_ELEMENTS};
+use super::constants::{BLOCK_ELEMENTS, INLINE_ELEMENTS, PRESERVE_WHITESPACE_ELEMENTS};
+
+static STYLE_SEPARATOR_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"([;:]) ").unwrap());
 
 const ALWAYS_CLOSE: [&str; 20] = [
     "title", "style", "a", "strong", "small", "b", "u", "i", "em", "s", "code", "object", "table",
@@ -50,14 +55,16 @@ where
             has_custom_element: is_custom_element,
             ..Default::default()
         };
+        if let Some(style) = &self.scoped_style {
+            results.template += &format!(" {}", style.attribute);
+        }
         if wrap_svg {
             results.template = "<svg>".to_string() + results.template.as_str();
         }
         if !info.skip_id {
             results.id = Some(self.generate_uid_identifier("el$"));
         }
-        let mut node = node.clone();
-        self.transform_attributes(&mut node, &mut results);
+        let children_attr = self.transform_attributes(node, &mut results);
         if self.config.context_to_custom_elements && (tag_name == "slot" || is_custom_element) {
             self.context_to_custom_element(&mut results);
         }
@@ -82,7 +89,13 @@ where
             } else {
                 results.to_be_closed = info.to_be_closed.clone();
             }
-            self.transform_children(&node, &mut results);
+            let preserve_whitespace = info.preserve_whitespace
+                || PRESERVE_WHITESPACE_ELEMENTS.contains(&tag_name.as_str());
+            let children = match children_attr {
+                Some(ref child) => std::slice::from_ref(child),
+                None => &node.children[..],
+            };
+            self.transform_children(children, &mut results, preserve_whitespace);
             if to_be_closed {
                 results.template += &format!("</{}>", tag_name);
             }
@@ -160,7 +173,7 @@ where
                     _ => {}
                 },
                 Expr::Ident(id) => {
-                    if id.sym.to_string() == "undefined" {
+                    if &id.sym == "undefined" {
                         return Expr::Call(CallExpr {
                             span: DUMMY_SP,
                             callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
@@ -252,19 +265,41 @@ where
                     },
                     ExprOrSpread {
                         spread: None,
-                        expr: Box::new(if options.dynamic {
-                            value.clone()
-                        } else {
-                            Expr::Unary(UnaryExpr {
-                                span: DUMMY_SP,
-                                op: UnaryOp::Bang,
-                                arg: Box::new(Expr::Unary(UnaryExpr {
+                        expr: Box::new(
+                            if options.dynamic
+                                || matches!(
+                                    value,
+                                    Expr::Lit(Lit::Bool(_))
+                                        | Expr::Unary(UnaryExpr {
+                                            op: UnaryOp::Bang,
+                                            ..
+                                        })
+                                        | Expr::Bin(BinExpr {
+                                            op: BinaryOp::EqEqEq
+                                                | BinaryOp::NotEqEq
+                                                | BinaryOp::EqEq
+                                                | BinaryOp::NotEq
+                                                | BinaryOp::Lt
+                                                | BinaryOp::LtEq
+                                                | BinaryOp::Gt
+                                                | BinaryOp::GtEq,
+                                            ..
+                                        })
+                                )
+                            {
+                                value.clone()
+                            } else {
+                                Expr::Unary(UnaryExpr {
                                     span: DUMMY_SP,
                                     op: UnaryOp::Bang,
-                                    arg: Box::new(value.clone()),
-                                })),
-                            })
-                        }),
+                                    arg: Box::new(Expr::Unary(UnaryExpr {
+                                        span: DUMMY_SP,
+                                        op: UnaryOp::Bang,
+                                        arg: Box::new(value.clone()),
+                                    })),
+                                })
+                            },
+                        ),
                     },
                 ],
                 type_args: None,
@@ -480,30 +515,71 @@ where
 {
     fn detect_resolvable_event_handler(&self, handler: &Expr) -> bool {
         if let Expr::Ident(id) = handler {
-            if let Some(init) = self.binding_collector.const_var_bindings.get(&id.to_id()) {
-                if let Some(init) = init {
-                    return self.detect_resolvable_event_handler(&init.clone());
-                } else {
-                    return false;
-                }
-            } else {
-                return self
+            return match self.binding_collector.const_var_bindings.get(&id.to_id()) {
+                Some(ConstInit::Foldable(init)) => self.detect_resolvable_event_handler(init),
+                Some(init) => matches!(init, ConstInit::Function),
+                None => self
                     .binding_collector
                     .function_bindings
-                    .contains(&id.to_id());
-            }
+                    .contains(&id.to_id()),
+            };
         }
         matches!(handler, Expr::Fn(_) | Expr::Arrow(_))
     }
 
-    fn transform_attributes(&mut self, node: &mut JSXElement, results: &mut TemplateInstantiation) {
+    /// Compiles the attributes of `node` into `results`. Returns the value of a
+    /// `children` attribute when the element has no JSX children of its own.
+    fn transform_attributes(
+        &mut self,
+        node: &JSXElement,
+        results: &mut TemplateInstantiation,
+    ) -> Option<JSXElementChild> {
         let elem = &results.id;
         let mut children = None;
         let mut spread_expr = Expr::Invalid(Invalid { span: DUMMY_SP });
+        self.warn_uncalled_functions_in_attributes(&node.opening.attrs);
         let mut attributes = node.opening.attrs.clone();
         let is_svg = SVG_ELEMENTS.contains(&results.tag_name.as_str());
         let is_ce = results.tag_name.contains('-');
         let has_children = !node.children.is_empty();
+        // Options inserted at runtime don't exist yet when the attributes are
+        // applied, so the selection has to be set once they are in place.
+        let select_with_dynamic_options =
+            results.tag_name == "select" && self.has_dynamic_children(&node.children);
+
+        // `delegate:false` attaches the element's handlers directly
+        let mut delegate = true;
+        attributes.retain(|attribute| match attribute {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::JSXNamespacedName(name),
+                ..
+            }) if &name.ns.sym == "delegate" => {
+                delegate = &name.name.sym != "false";
+                false
+            }
+            _ => true,
+        });
+
+        if self.config.react_compat {
+            attributes = self.process_react_compat(attributes, &results.tag_name);
+        }
+
+        if is_ce {
+            attributes = self.process_custom_element(attributes, &results.tag_name);
+        }
+
+        if self.config.transform_asset_urls {
+            attributes = self.process_asset_urls(attributes, &results.tag_name);
+        }
+
+        if self.config.hoist_event_handlers {
+            attributes = self.hoist_event_handlers(attributes);
+        }
+
+        // preprocess two-way bindings
+        if attributes.iter().any(is_binding) {
+            attributes = process_bindings(attributes, &results.tag_name);
+        }
 
         // preprocess spreads
         if attributes.iter().any(|attribute| match attribute {
@@ -542,8 +618,19 @@ where
         });
         if let Some((style_idx, mut props, span)) = style_props {
             let mut i = 0usize;
+            let mut static_styles = vec![];
             props.retain(|prop| {
                 let mut handle = |name: Ident, value: Expr| {
+                    match self.evaluate(&value) {
+                        Some(Lit::Null(_)) | Some(Lit::Bool(Bool { value: false, .. })) => {
+                            return false
+                        }
+                        Some(lit @ (Lit::Str(_) | Lit::Num(_))) => {
+                            static_styles.push(format!("{}:{}", name.sym, lit_to_string(&lit)));
+                            return false;
+                        }
+                        _ => {}
+                    }
                     i += 1;
                     attributes.insert(
                         style_idx + i,
@@ -592,6 +679,31 @@ where
                     })),
                 });
             }
+            // literal values are folded into a single static `style` attribute
+            if !static_styles.is_empty() {
+                let css = static_styles.join(";");
+                let existing = attributes.iter_mut().find_map(|attribute| match attribute {
+                    JSXAttrOrSpread::JSXAttr(JSXAttr {
+                        name: JSXAttrName::Ident(name),
+                        value: Some(JSXAttrValue::Lit(Lit::Str(value))),
+                        ..
+                    }) if &name.sym == "style" => Some(value),
+                    _ => None,
+                });
+                if let Some(value) = existing {
+                    let current = value.value.trim().trim_end_matches(';').to_string();
+                    *value = format!("{};{}", current, css).into();
+                } else {
+                    attributes.insert(
+                        style_idx.min(attributes.len()),
+                        JSXAttrOrSpread::JSXAttr(JSXAttr {
+                            span: DUMMY_SP,
+                            name: JSXAttrName::Ident(quote_ident!("style")),
+                            value: Some(JSXAttrValue::Lit(Lit::Str(css.into()))),
+                        }),
+                    );
+                }
+            }
         }
 
         // preprocess classList
@@ -634,8 +746,9 @@ where
             props.retain(|prop| {
                 let mut handle = |name: Ident, value: Expr| {
                     i += 1;
-                    match self.evaluator.as_mut().unwrap().eval(&value) {
-                        Some(EvalResult::Lit(_)) => {
+                    match self.evaluate(&value) {
+                        Some(lit) if !is_truthy(&lit) => i -= 1,
+                        Some(_) => {
                             attributes.insert(
                                 class_list_idx + i,
                                 JSXAttrOrSpread::JSXAttr(JSXAttr {
@@ -700,6 +813,9 @@ where
             }
         }
 
+        // preprocess clsx-style class arrays and objects
+        attributes = self.process_class_names(attributes);
+
         // combine class properties
         let class_attributes: Vec<_> = attributes
             .iter()
@@ -811,8 +927,8 @@ where
                     ..
                 })) = attribute.value
                 {
-                    match self.evaluator.as_mut().unwrap().eval(expr) {
-                        Some(EvalResult::Lit(lit)) if matches!(lit, Lit::Str(_) | Lit::Num(_)) => {
+                    match self.evaluate(expr) {
+                        Some(lit) if matches!(lit, Lit::Str(_) | Lit::Num(_)) => {
                             attribute.value = Some(JSXAttrValue::Lit(lit))
                         }
                         _ => {}
@@ -1075,6 +1191,7 @@ where
                                 type_args: None,
                             }))
                         } else if self.config.delegate_events
+                            && delegate
                             && (DELEGATED_EVENTS.contains(&ev.as_ref())
                                 || self.config.delegated_events.contains(&ev.to_string()))
                         {
@@ -1289,10 +1406,7 @@ where
                     } else if !self.config.effect_wrapper.is_empty()
                         && (self.is_dynamic(&*expr, Some(*span), true, false, true, false)
                             || ((key == "classList" || key == "style")
-                                && !(matches!(
-                                    self.evaluator.as_mut().unwrap().eval(&*expr),
-                                    Some(EvalResult::Lit(_))
-                                ) || is_static_expr(&*expr))))
+                                && !(self.evaluate(expr).is_some() || is_static_expr(&*expr))))
                     {
                         let mut next_elem = elem.clone().unwrap();
                         if key == "value" || key == "checked" {
@@ -1358,6 +1472,20 @@ where
                             is_ce,
                             tag_name: results.tag_name.clone(),
                         });
+                    } else if key == "value" && select_with_dynamic_options {
+                        let assign = self.set_attr(
+                            elem.as_ref().unwrap(),
+                            &key,
+                            expr,
+                            &AttrOptions {
+                                is_svg,
+                                dynamic: false,
+                                prev_id: None,
+                                is_ce,
+                                tag_name: results.tag_name.clone(),
+                            },
+                        );
+                        results.post_exprs.push(assign);
                     } else {
                         results.exprs.push(self.set_attr(
                             &elem.clone().unwrap(),
@@ -1432,14 +1560,8 @@ where
                             if key == "style" || key == "class" {
                                 text = trim_whitespace(&text);
                                 if key == "style" {
-                                    text = Regex::new(r"; ")
-                                        .unwrap()
-                                        .replace_all(&text, ";")
-                                        .to_string();
-                                    text = Regex::new(r": ")
-                                        .unwrap()
-                                        .replace_all(&text, ":")
-                                        .to_string();
+                                    text =
+                                        STYLE_SEPARATOR_REGEX.replace_all(&text, "$1").to_string();
                                 }
                             }
                             results.template +=
@@ -1452,15 +1574,15 @@ where
             }
         }
 
-        if !has_children {
-            if let Some(child) = children {
-                node.children.push(child);
-            }
-        }
-
         if !matches!(spread_expr, Expr::Invalid(_)) {
             results.exprs.push(spread_expr);
         }
+
+        if has_children {
+            None
+        } else {
+            children
+        }
     }
 
     fn context_to_custom_element(&mut self, results: &mut TemplateInstantiation) {
@@ -1483,6 +1605,22 @@ where
         }))
     }
 
+    fn has_dynamic_children(&mut self, children: &[JSXElementChild]) -> bool {
+        flatten_children(children, self.config.whitespace)
+            .into_iter()
+            .any(|child| match child {
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(_),
+                    ..
+                }) => self.get_static_expression(child).is_none(),
+                JSXElementChild::JSXElement(element) => {
+                    let tag_name = get_tag_name(element);
+                    is_component(&tag_name) || self.has_dynamic_children(&element.children)
+                }
+                _ => false,
+            })
+    }
+
     fn process_spreads(
         &mut self,
         attributes: Vec<JSXAttrOrSpread>,
@@ -1691,32 +1829,42 @@ impl<C> TransformVisitor<C>
 where
     C: Comments,
 {
-    fn transform_children(&mut self, node: &JSXElement, results: &mut TemplateInstantiation) {
+    fn transform_children(
+        &mut self,
+        children: &[JSXElementChild],
+        results: &mut TemplateInstantiation,
+        preserve_whitespace: bool,
+    ) {
+        self.warn_uncalled_functions_in_children(children);
+        let mapped;
+        let children = if self.config.map_children_to_for {
+            mapped = self.map_children_to_for(children);
+            &mapped[..]
+        } else {
+            children
+        };
         let mut temp_path = results.id.clone();
         let mut next_placeholder = None;
         let mut i = 0;
-        let filtered_children = node
-            .children
-            .iter()
-            .filter(|c| filter_children(c))
-            .collect::<Vec<&JSXElementChild>>();
+        let whitespace = if preserve_whitespace {
+            Whitespace::Preserve
+        } else {
+            self.config.whitespace
+        };
+        let filtered_children = flatten_children(children, whitespace);
         let last_element = self.find_last_element(&filtered_children);
-        let child_nodes = filtered_children.iter().enumerate().fold(
+        let mut child_nodes = filtered_children.iter().enumerate().fold(
             Vec::<TemplateInstantiation>::new(),
             |mut memo, (index, child)| {
-                if let JSXElementChild::JSXFragment(_) = child {
-                    panic!(
-                        "Fragments can only be used top level in JSX. Not used under a <{}>.",
-                        results.tag_name
-                    );
-                }
-                let detect_expressions = self.detect_expressions(&filtered_children, index);
+                let detect_expressions =
+                    self.detect_expressions(&filtered_children, index, whitespace);
                 let transformed = self.transform_node(
                     child,
                     &TransformInfo {
                         to_be_closed: results.to_be_closed.clone(),
                         last_element: index == last_element as usize,
                         skip_id: results.id.is_none() || !detect_expressions,
+                        preserve_whitespace,
                         ..Default::default()
                     },
                 );
@@ -1735,11 +1883,12 @@ where
             },
         );
 
-        child_nodes.iter().enumerate().for_each(|(index, child)| {
+        for index in 0..child_nodes.len() {
+            let child = &mut child_nodes[index];
             results.template += &child.template;
             if child.id.is_some() {
                 if child.tag_name == "head" {
-                    return;
+                    continue;
                 }
 
                 let walk = Expr::Member(MemberExpr {
@@ -1760,21 +1909,18 @@ where
                     init: Some(Box::new(walk)),
                     definite: false,
                 });
-                results
-                    .declarations
-                    .extend(child.declarations.clone().into_iter());
-                results.exprs.extend(child.exprs.clone().into_iter());
-                results.dynamics.extend(child.dynamics.clone().into_iter());
-                results
-                    .post_exprs
-                    .extend(child.post_exprs.clone().into_iter());
+                results.declarations.append(&mut child.declarations);
+                results.exprs.append(&mut child.exprs);
+                results.dynamics.append(&mut child.dynamics);
+                results.post_exprs.append(&mut child.post_exprs);
                 results.has_custom_element |= child.has_custom_element;
                 temp_path = child.id.clone();
                 next_placeholder = None;
                 i += 1;
             } else if !child.exprs.is_empty() {
+                let child_expr = child.exprs.swap_remove(0);
                 let insert = self.register_import_method("insert");
-                let multi = check_length(&filtered_children);
+                let multi = check_length(&filtered_children, whitespace);
 
                 if wrapped_by_text(&child_nodes, index) {
                     let expr_id;
@@ -1797,7 +1943,7 @@ where
                                 },
                                 ExprOrSpread {
                                     spread: None,
-                                    expr: child.exprs[0].clone().into(),
+                                    expr: child_expr.into(),
                                 },
                                 ExprOrSpread {
                                     spread: None,
@@ -1813,7 +1959,7 @@ where
                                 },
                                 ExprOrSpread {
                                     spread: None,
-                                    expr: child.exprs[0].clone().into(),
+                                    expr: child_expr.into(),
                                 },
                                 ExprOrSpread {
                                     spread: None,
@@ -1835,7 +1981,7 @@ where
                             },
                             ExprOrSpread {
                                 spread: None,
-                                expr: child.exprs[0].clone().into(),
+                                expr: child_expr.into(),
                             },
                             next_child(&child_nodes, index)
                                 .unwrap_or(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })))
@@ -1854,7 +2000,7 @@ where
                             },
                             ExprOrSpread {
                                 spread: None,
-                                expr: child.exprs[0].clone().into(),
+                                expr: child_expr.into(),
                             },
                         ],
                         type_args: Default::default(),
@@ -1863,7 +2009,7 @@ where
             } else {
                 next_placeholder = None;
             }
-        });
+        }
     }
 
     fn create_placeholder(
@@ -1895,7 +2041,12 @@ where
         (expr_id, None)
     }
 
-    fn detect_expressions(&mut self, children: &[&JSXElementChild], index: usize) -> bool {
+    fn detect_expressions(
+        &mut self,
+        children: &[&JSXElementChild],
+        index: usize,
+        whitespace: Whitespace,
+    ) -> bool {
         if index > 0 {
             let node = &children[index - 1];
 
@@ -1950,17 +2101,20 @@ where
                             }
                         } else {
                             false
-                        }))
+                        }) || self.is_asset_url_attr(attr, &tag_name))
                     }
                 }) {
                     return true;
                 }
-                let next_children = e
-                    .children
-                    .iter()
-                    .filter(|c| filter_children(c))
-                    .collect::<Vec<&JSXElementChild>>();
-                if !next_children.is_empty() && self.detect_expressions(&next_children, 0) {
+                let whitespace = if PRESERVE_WHITESPACE_ELEMENTS.contains(&tag_name.as_str()) {
+                    Whitespace::Preserve
+                } else {
+                    whitespace
+                };
+                let next_children = flatten_children(&e.children, whitespace);
+                if !next_children.is_empty()
+                    && self.detect_expressions(&next_children, 0, whitespace)
+                {
                     return true;
                 }
             }
@@ -2001,3 +2155,210 @@ fn next_child(child_nodes: &Vec<TemplateInstantiation>, index: usize) -> Option<
         None
     }
 }
+
+fn is_binding(attribute: &JSXAttrOrSpread) -> bool {
+    matches!(
+        attribute,
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, .. }),
+            ..
+        }) if &ns.sym == "bind"
+    )
+}
+
+/// Expands `bind:value={[get, set]}` and `bind:checked={signal}` into the
+/// property they keep up to date and the event handler writing back through
+/// the setter, so both go through the regular attribute handling.
+fn process_bindings(attributes: Vec<JSXAttrOrSpread>, tag_name: &str) -> Vec<JSXAttrOrSpread> {
+    let input_type = attributes.iter().find_map(|attribute| match attribute {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(name),
+            value: Some(JSXAttrValue::Lit(Lit::Str(value))),
+            ..
+        }) if &name.sym == "type" => Some(value.value.to_string()),
+        _ => None,
+    });
+
+    // Events handled by the element itself, lowercased.
+    let handled_events: Vec<_> = attributes
+        .iter()
+        .filter_map(|attribute| match attribute {
+            JSXAttrOrSpread::JSXAttr(JSXAttr { name, .. }) => match name {
+                JSXAttrName::Ident(name) => name
+                    .sym
+                    .to_lowercase()
+                    .strip_prefix("on")
+                    .map(str::to_owned),
+                JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name })
+                    if matches!(&*ns.sym, "on" | "oncapture") =>
+                {
+                    Some(name.sym.to_lowercase())
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let mut processed = vec![];
+    for attribute in attributes {
+        if !is_binding(&attribute) {
+            processed.push(attribute);
+            continue;
+        }
+        let JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::JSXNamespacedName(JSXNamespacedName { name, .. }),
+            value,
+            span,
+        }) = attribute
+        else {
+            unreachable!()
+        };
+        let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            span: value_span,
+        })) = value
+        else {
+            emit_error(
+                span,
+                &format!(
+                    "bind:{} expects a [getter, setter] pair or a signal",
+                    name.sym
+                ),
+            );
+            continue;
+        };
+
+        let (prop, event, field) = match (&*name.sym, tag_name) {
+            ("value", "select") => ("value", "onChange", "value"),
+            ("value", _) if matches!(input_type.as_deref(), Some("number" | "range")) => {
+                ("prop:valueAsNumber", "onInput", "valueAsNumber")
+            }
+            ("value", _) => ("value", "onInput", "value"),
+            ("checked", _) => ("checked", "onChange", "checked"),
+            _ => {
+                emit_error(
+                    span,
+                    &format!(
+                        "bind: only supports value and checked, found bind:{}",
+                        name.sym
+                    ),
+                );
+                continue;
+            }
+        };
+        if handled_events
+            .iter()
+            .any(|handled| handled == &event[2..].to_lowercase())
+        {
+            emit_error(
+                span,
+                &format!(
+                    "bind:{} sets `{}`, which the element already handles; update the value \
+                     from the setter instead",
+                    name.sym, event
+                ),
+            );
+            continue;
+        }
+
+        let (getter, setter) = match *expr {
+            Expr::Array(ArrayLit { elems, .. })
+                if elems.len() == 2
+                    && elems
+                        .iter()
+                        .all(|e| matches!(e, Some(e) if e.spread.is_none())) =>
+            {
+                let mut elems = elems.into_iter().map(|e| e.unwrap().expr);
+                (elems.next().unwrap(), elems.next().unwrap())
+            }
+            // The signal is indexed twice, so only accept expressions that are
+            // safe to evaluate more than once.
+            expr @ (Expr::Ident(_) | Expr::Member(_)) => {
+                let index = |i: f64| {
+                    Box::new(Expr::Member(MemberExpr {
+                        span: DUMMY_SP,
+                        obj: Box::new(expr.clone()),
+                        prop: MemberProp::Computed(ComputedPropName {
+                            span: DUMMY_SP,
+                            expr: Box::new(Expr::Lit(Lit::Num(i.into()))),
+                        }),
+                    }))
+                };
+                (index(0.0), index(1.0))
+            }
+            _ => {
+                emit_error(
+                    span,
+                    &format!(
+                        "bind:{} expects a [getter, setter] pair or a signal variable",
+                        name.sym
+                    ),
+                );
+                continue;
+            }
+        };
+
+        let call = |callee: Box<Expr>, args: Vec<ExprOrSpread>| {
+            Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(match *callee {
+                    callee @ (Expr::Arrow(_) | Expr::Fn(_)) => Box::new(Expr::Paren(ParenExpr {
+                        span: DUMMY_SP,
+                        expr: Box::new(callee),
+                    })),
+                    callee => Box::new(callee),
+                }),
+                args,
+                type_args: None,
+            })
+        };
+        let event_id = private_ident!("e");
+        let handler = Expr::Arrow(ArrowExpr {
+            span: DUMMY_SP,
+            params: vec![Pat::Ident(event_id.clone().into())],
+            body: Box::new(BlockStmtOrExpr::Expr(Box::new(call(
+                setter,
+                vec![Expr::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: Box::new(Expr::Member(MemberExpr {
+                        span: DUMMY_SP,
+                        obj: Box::new(Expr::Ident(event_id)),
+                        prop: MemberProp::Ident(quote_ident!("currentTarget")),
+                    })),
+                    prop: MemberProp::Ident(quote_ident!(field)),
+                })
+                .into()],
+            )))),
+            is_async: false,
+            is_generator: false,
+            type_params: None,
+            return_type: None,
+        });
+
+        let prop_name = match prop.split_once(':') {
+            Some((ns, name)) => JSXAttrName::JSXNamespacedName(JSXNamespacedName {
+                ns: quote_ident!(ns),
+                name: quote_ident!(name),
+            }),
+            None => JSXAttrName::Ident(quote_ident!(prop)),
+        };
+        processed.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span,
+            name: prop_name,
+            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                span: value_span,
+                expr: JSXExpr::Expr(Box::new(call(getter, vec![]))),
+            })),
+        }));
+        processed.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span,
+            name: JSXAttrName::Ident(quote_ident!(event)),
+            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                span: DUMMY_SP,
+                expr: JSXExpr::Expr(Box::new(handler)),
+            })),
+        }));
+    }
+    processed
+}
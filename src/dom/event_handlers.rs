@@ -0,0 +1,250 @@
+use crate::{
+    shared::{transform::ConstInit, utils::convert_jsx_identifier},
+    TransformVisitor,
+};
+use swc_core::{
+    common::{collections::AHashSet, comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{find_pat_ids, prepend_stmt},
+        visit::{Visit, VisitMutWith, VisitWith},
+    },
+};
+
+/// Collects the identifiers a function reads from outer scopes, and whether it
+/// depends on the `this`, `arguments` or `new.target` of its surroundings.
+#[derive(Default)]
+struct FreeVariableFinder {
+    declared: AHashSet<Id>,
+    referenced: Vec<Id>,
+    captures_scope: bool,
+    /// Number of non-arrow functions around the visited node.
+    depth: usize,
+}
+
+impl FreeVariableFinder {
+    fn declare(&mut self, pat: &Pat) {
+        self.declared.extend(find_pat_ids::<_, Id>(pat));
+    }
+}
+
+impl Visit for FreeVariableFinder {
+    fn visit_function(&mut self, n: &Function) {
+        self.depth += 1;
+        n.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_class(&mut self, n: &Class) {
+        self.depth += 1;
+        n.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_this_expr(&mut self, _: &ThisExpr) {
+        self.captures_scope |= self.depth == 0;
+    }
+
+    fn visit_super(&mut self, _: &Super) {
+        self.captures_scope |= self.depth == 0;
+    }
+
+    fn visit_meta_prop_expr(&mut self, n: &MetaPropExpr) {
+        self.captures_scope |= self.depth == 0 && n.kind == MetaPropKind::NewTarget;
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        match n {
+            Expr::Ident(id) if &id.sym == "arguments" => {
+                self.captures_scope |= self.depth == 0;
+            }
+            Expr::Ident(id) => self.referenced.push(id.to_id()),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_prop(&mut self, n: &Prop) {
+        if let Prop::Shorthand(id) = n {
+            self.referenced.push(id.to_id());
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName) {
+        let id = match n {
+            JSXElementName::Ident(id) if id.sym.starts_with(|c: char| c.is_ascii_lowercase()) => {
+                return;
+            }
+            JSXElementName::Ident(id) => id,
+            JSXElementName::JSXMemberExpr(expr) => {
+                let mut obj = &expr.obj;
+                loop {
+                    match obj {
+                        JSXObject::Ident(id) => break id,
+                        JSXObject::JSXMemberExpr(expr) => obj = &expr.obj,
+                    }
+                }
+            }
+            JSXElementName::JSXNamespacedName(_) => return,
+        };
+        if &id.sym == "this" {
+            self.captures_scope |= self.depth == 0;
+        } else {
+            self.referenced.push(id.to_id());
+        }
+    }
+
+    // Bindings reached outside of declarations are assignment targets.
+    fn visit_binding_ident(&mut self, n: &BindingIdent) {
+        self.referenced.push(n.id.to_id());
+    }
+
+    fn visit_assign_pat_prop(&mut self, n: &AssignPatProp) {
+        self.referenced.push(n.key.to_id());
+        n.value.visit_with(self);
+    }
+
+    fn visit_param(&mut self, n: &Param) {
+        self.declare(&n.pat);
+        n.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        for param in &n.params {
+            self.declare(param);
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause) {
+        if let Some(param) = &n.param {
+            self.declare(param);
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+        self.declare(&n.name);
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl) {
+        self.declared.insert(n.ident.to_id());
+        n.function.visit_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr) {
+        if let Some(ident) = &n.ident {
+            self.declared.insert(ident.to_id());
+        }
+        n.function.visit_with(self);
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.declared.insert(n.ident.to_id());
+        n.class.visit_with(self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr) {
+        if let Some(ident) = &n.ident {
+            self.declared.insert(ident.to_id());
+        }
+        n.class.visit_with(self);
+    }
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Whether `handler` is a function that only reads module-level or global
+    /// bindings, so a single instance can be shared by every element.
+    fn is_hoistable_handler(&self, handler: &Expr) -> bool {
+        if !matches!(handler, Expr::Fn(_) | Expr::Arrow(_)) {
+            return false;
+        }
+        let mut finder = FreeVariableFinder::default();
+        handler.visit_with(&mut finder);
+        !finder.captures_scope
+            && finder.referenced.iter().all(|id| {
+                finder.declared.contains(id)
+                    || self.binding_collector.module_bindings.contains(id)
+                    || id.1.outer() == self.unresolved_mark
+            })
+    }
+
+    fn hoist_handler(&mut self, handler: &mut Box<Expr>) {
+        if !self.is_hoistable_handler(handler) {
+            return;
+        }
+        let id = self.generate_uid_identifier("handler$");
+        let handler = std::mem::replace(handler, Box::new(Expr::Ident(id.clone())));
+        // lets the handler be assigned directly, as the inline function was
+        self.binding_collector
+            .const_var_bindings
+            .insert(id.to_id(), ConstInit::Function);
+        self.hoisted_handlers.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(id.into()),
+            init: Some(handler),
+            definite: false,
+        });
+    }
+
+    /// Moves inline event handlers that don't close over local bindings to
+    /// module-level constants, in both the function and `[handler, data]`
+    /// forms.
+    pub fn hoist_event_handlers(
+        &mut self,
+        mut attributes: Vec<JSXAttrOrSpread>,
+    ) -> Vec<JSXAttrOrSpread> {
+        for attribute in &mut attributes {
+            let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name,
+                value:
+                    Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        expr: JSXExpr::Expr(expr),
+                        ..
+                    })),
+                ..
+            }) = attribute
+            else {
+                continue;
+            };
+            if !convert_jsx_identifier(name).1.starts_with("on") {
+                continue;
+            }
+            match &mut **expr {
+                Expr::Array(ArrayLit { elems, .. }) => {
+                    if let Some(Some(ExprOrSpread { spread: None, expr })) = elems.first_mut() {
+                        self.hoist_handler(expr);
+                    }
+                }
+                _ => self.hoist_handler(expr),
+            }
+        }
+        attributes
+    }
+
+    pub fn insert_hoisted_handlers(&mut self, module: &mut Module) {
+        let mut decls = vec![];
+        // handlers may contain JSX with handlers of their own
+        while !self.hoisted_handlers.is_empty() {
+            let mut handlers = std::mem::take(&mut self.hoisted_handlers);
+            handlers.visit_mut_with(self);
+            decls.extend(handlers);
+        }
+        if decls.is_empty() {
+            return;
+        }
+        prepend_stmt(
+            &mut module.body,
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls,
+            })))),
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use super::constants::UNITLESS_STYLE_PROPERTIES;
+use crate::{
+    shared::utils::{emit_warning, lit_to_string},
+    TransformVisitor,
+};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{ast::*, utils::quote_ident},
+};
+
+const TEXT_INPUT_TYPES: [&str; 8] = [
+    "text", "search", "email", "url", "tel", "password", "number", "",
+];
+
+fn attr_name(attr: &JSXAttr) -> Option<&str> {
+    match &attr.name {
+        JSXAttrName::Ident(name) => Some(&name.sym),
+        JSXAttrName::JSXNamespacedName(_) => None,
+    }
+}
+
+/// Converts a React style key such as `fontSize` or `WebkitTransition` into
+/// its CSS property name.
+fn css_property_name(key: &str) -> String {
+    if key.starts_with("--") {
+        return key.to_string();
+    }
+    let mut name = String::new();
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            name.push('-');
+            name.push(c.to_ascii_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    if name.starts_with("ms-") {
+        name.insert(0, '-');
+    }
+    name
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Rewrites React-only attributes of a DOM element into their equivalents
+    /// here, warning about each one so they can be migrated at the source.
+    pub fn process_react_compat(
+        &self,
+        attributes: Vec<JSXAttrOrSpread>,
+        tag_name: &str,
+    ) -> Vec<JSXAttrOrSpread> {
+        let input_type = attributes.iter().find_map(|attribute| match attribute {
+            JSXAttrOrSpread::JSXAttr(
+                attr @ JSXAttr {
+                    value: Some(JSXAttrValue::Lit(Lit::Str(value))),
+                    ..
+                },
+            ) if attr_name(attr) == Some("type") => Some(value.value.to_string()),
+            _ => None,
+        });
+        let is_text_input = tag_name == "textarea"
+            || (tag_name == "input"
+                && TEXT_INPUT_TYPES.contains(&input_type.as_deref().unwrap_or("")));
+
+        attributes
+            .into_iter()
+            .filter_map(|attribute| {
+                let JSXAttrOrSpread::JSXAttr(mut attr) = attribute else {
+                    return Some(attribute);
+                };
+                let name = attr_name(&attr).map(str::to_string);
+                match name.as_deref() {
+                    Some("key") => {
+                        emit_warning(
+                            attr.span,
+                            "`key` has no effect outside of React and was removed",
+                        );
+                        return None;
+                    }
+                    Some("onChange") if is_text_input => {
+                        emit_warning(
+                            attr.span,
+                            "`onChange` on text inputs fires on every keystroke in React; it \
+                             was compiled to `onInput`",
+                        );
+                        attr.name = JSXAttrName::Ident(quote_ident!("onInput"));
+                    }
+                    Some("dangerouslySetInnerHTML") => {
+                        emit_warning(
+                            attr.span,
+                            "`dangerouslySetInnerHTML` was compiled to `innerHTML`",
+                        );
+                        attr.name = JSXAttrName::Ident(quote_ident!("innerHTML"));
+                        attr.value = attr.value.map(inner_html_value);
+                    }
+                    Some(name @ ("defaultValue" | "defaultChecked")) => {
+                        let attribute = if name == "defaultValue" {
+                            "value"
+                        } else {
+                            "checked"
+                        };
+                        emit_warning(
+                            attr.span,
+                            &format!("`{}` was compiled to the `{}` attribute", name, attribute),
+                        );
+                        return default_attr(attr, name, attribute, tag_name)
+                            .map(JSXAttrOrSpread::JSXAttr);
+                    }
+                    Some("style") => attr.value = attr.value.map(react_style),
+                    _ => {}
+                }
+                Some(JSXAttrOrSpread::JSXAttr(attr))
+            })
+            .collect()
+    }
+}
+
+/// `{{ __html: value }}` becomes `value`, anything else `expr.__html`.
+fn inner_html_value(value: JSXAttrValue) -> JSXAttrValue {
+    let JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        span,
+    }) = value
+    else {
+        return value;
+    };
+    let html = match &*expr {
+        Expr::Object(ObjectLit { props, .. }) => match props.as_slice() {
+            [PropOrSpread::Prop(prop)] => match &**prop {
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(key),
+                    value,
+                }) if &key.sym == "__html" => Some(value.clone()),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    };
+    let html = html.unwrap_or_else(|| {
+        Box::new(Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: expr,
+            prop: MemberProp::Ident(quote_ident!("__html")),
+        }))
+    });
+    JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        span,
+        expr: JSXExpr::Expr(html),
+    })
+}
+
+/// Literal defaults go into the template as plain attributes; expressions
+/// set the reflecting `defaultValue`/`defaultChecked` property instead. A
+/// `<textarea>` has no `value` attribute, so it always uses the property.
+fn default_attr(attr: JSXAttr, name: &str, attribute: &str, tag_name: &str) -> Option<JSXAttr> {
+    let literal = match &attr.value {
+        Some(JSXAttrValue::Lit(lit)) => Some(lit.clone()),
+        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+        })) => expr.as_lit().cloned(),
+        None => Some(Lit::Bool(true.into())),
+        _ => None,
+    };
+    if tag_name != "textarea" {
+        match literal {
+            Some(Lit::Bool(Bool { value: false, .. })) => return None,
+            Some(Lit::Bool(_)) => {
+                return Some(JSXAttr {
+                    name: JSXAttrName::Ident(quote_ident!(attribute)),
+                    value: None,
+                    ..attr
+                })
+            }
+            Some(lit @ (Lit::Str(_) | Lit::Num(_))) => {
+                return Some(JSXAttr {
+                    name: JSXAttrName::Ident(quote_ident!(attribute)),
+                    value: Some(JSXAttrValue::Lit(Lit::Str(lit_to_string(&lit).into()))),
+                    ..attr
+                })
+            }
+            _ => {}
+        }
+    }
+    Some(JSXAttr {
+        name: JSXAttrName::JSXNamespacedName(JSXNamespacedName {
+            ns: quote_ident!("prop"),
+            name: quote_ident!(name),
+        }),
+        ..attr
+    })
+}
+
+/// Renames camelCase keys of a `style` object to CSS properties and adds
+/// `px` to numbers for properties that aren't unitless.
+fn react_style(value: JSXAttrValue) -> JSXAttrValue {
+    let JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(mut expr),
+        span,
+    }) = value
+    else {
+        return value;
+    };
+    if let Expr::Object(ObjectLit { props, .. }) = &mut *expr {
+        for prop in props.iter_mut() {
+            let PropOrSpread::Prop(prop) = prop else {
+                continue;
+            };
+            let (key, mut value) = match &**prop {
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(key),
+                    value,
+                }) => (key.clone(), value.clone()),
+                Prop::Shorthand(id) => (id.clone(), Box::new(Expr::Ident(id.clone()))),
+                _ => continue,
+            };
+            let css_name = css_property_name(&key.sym);
+            if css_name != *key.sym {
+                emit_warning(
+                    key.span,
+                    &format!("style key `{}` was converted to `{}`", key.sym, css_name),
+                );
+            }
+            if let Expr::Lit(Lit::Num(number)) = &*value {
+                if number.value != 0.0 && !UNITLESS_STYLE_PROPERTIES.contains(&&*key.sym) {
+                    emit_warning(
+                        key.span,
+                        &format!("`px` was added to the number for `{}`", key.sym),
+                    );
+                    value = Box::new(Expr::Lit(Lit::Str(format!("{}px", number.value).into())));
+                }
+            }
+            **prop = Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(css_name.into()),
+                value,
+            });
+        }
+    }
+    JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        span,
+        expr: JSXExpr::Expr(expr),
+    })
+}
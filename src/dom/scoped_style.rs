@@ -0,0 +1,401 @@
+use crate::{
+    shared::{structs::ScopedStyle, utils::member_path},
+    TransformVisitor,
+};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::collections::HashSet;
+use swc_core::{
+    common::{comments::Comments, util::take::Take, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{prepend_stmt, private_ident, quote_ident},
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+/// At-rules whose blocks contain style rules that need scoping as well.
+const NESTED_AT_RULES: [&str; 5] = ["media", "supports", "container", "layer", "document"];
+
+static KEYFRAMES_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"@(-webkit-|-moz-)?keyframes\s+([\w-]+)").unwrap());
+static ANIMATION_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(animation(?:-name)?\s*:)([^;{}]*)([;}])").unwrap());
+static NAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w-]+").unwrap());
+
+/// Removes every `<style scoped>` element from the module and collects its
+/// CSS.
+#[derive(Default)]
+struct ScopedStyleCollector {
+    css: Vec<String>,
+}
+
+impl ScopedStyleCollector {
+    fn take_style(&mut self, element: &JSXElement) -> bool {
+        if !is_scoped_style(element) {
+            return false;
+        }
+        self.css.push(style_text(element));
+        true
+    }
+
+    fn retain_children(&mut self, children: &mut Vec<JSXElementChild>) {
+        children.retain(|child| match child {
+            JSXElementChild::JSXElement(element) => !self.take_style(element),
+            _ => true,
+        });
+    }
+}
+
+impl VisitMut for ScopedStyleCollector {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::JSXElement(element) = expr {
+            if self.take_style(element) {
+                *expr = Expr::Lit(Lit::Null(Null::dummy()));
+                return;
+            }
+        }
+        expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_jsx_element(&mut self, element: &mut JSXElement) {
+        self.retain_children(&mut element.children);
+        element.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_jsx_fragment(&mut self, fragment: &mut JSXFragment) {
+        self.retain_children(&mut fragment.children);
+        fragment.visit_mut_children_with(self);
+    }
+}
+
+fn is_scoped_style(element: &JSXElement) -> bool {
+    matches!(&element.opening.name, JSXElementName::Ident(id) if &id.sym == "style")
+        && element.opening.attrs.iter().any(|attr| {
+            matches!(attr, JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                ..
+            }) if &name.sym == "scoped")
+        })
+}
+
+fn style_text(element: &JSXElement) -> String {
+    element
+        .children
+        .iter()
+        .map(|child| match child {
+            JSXElementChild::JSXText(text) => text.value.to_string(),
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => match &**expr {
+                Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+                Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() => quasis[0]
+                    .cooked
+                    .as_ref()
+                    .unwrap_or(&quasis[0].raw)
+                    .to_string(),
+                _ => panic!("The CSS of a `<style scoped>` element must be static"),
+            },
+            JSXElementChild::JSXExprContainer(_) => String::new(),
+            _ => panic!("The CSS of a `<style scoped>` element must be static"),
+        })
+        .collect()
+}
+
+/// FNV-1a, so the attribute stays the same across builds and platforms.
+fn hash(text: &str) -> String {
+    let hash = text.bytes().fold(0x811c9dc5u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+    format!("{:08x}", hash)
+}
+
+/// Finds the first of `delimiters` outside of strings, parentheses and
+/// brackets.
+fn find_top_level(text: &str, delimiters: &[char]) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth -= 1,
+            (None, c) if depth == 0 && delimiters.contains(&c) => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn matching_brace(text: &str, open: usize) -> usize {
+    let mut depth = 0;
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return open + i;
+                }
+            }
+            _ => {}
+        }
+    }
+    panic!("Unbalanced braces in `<style scoped>`")
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut result = String::new();
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map_or("", |end| &rest[start + end + 4..]);
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Adds the attribute selector to the last compound selector, ahead of any
+/// pseudo-class or pseudo-element: `.list li:hover` becomes
+/// `.list li[data-s-…]:hover`.
+fn scope_selector(selector: &str, attribute: &str) -> String {
+    let selector = selector.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut start = 0;
+    let mut rest = selector.as_str();
+    while let Some(i) = find_top_level(rest, &[' ', '>', '+', '~']) {
+        start += i + 1;
+        rest = &rest[i + 1..];
+    }
+    let insert_at = start + find_top_level(rest, &[':']).unwrap_or(rest.len());
+    format!(
+        "{}[{}]{}",
+        &selector[..insert_at],
+        attribute,
+        &selector[insert_at..]
+    )
+}
+
+/// Suffixes the names of the `@keyframes` declared in `css` with `hash`, and
+/// renames them wherever an `animation` or `animation-name` declaration uses
+/// them, so animations of different files don't clash.
+fn scope_keyframes(css: &str, hash: &str) -> String {
+    let names: HashSet<_> = KEYFRAMES_REGEX
+        .captures_iter(css)
+        .map(|captures| captures[2].to_string())
+        .collect();
+    if names.is_empty() {
+        return css.to_string();
+    }
+    let css = KEYFRAMES_REGEX.replace_all(css, |captures: &Captures| {
+        format!(
+            "@{}keyframes {}-{}",
+            captures.get(1).map_or("", |prefix| prefix.as_str()),
+            &captures[2],
+            hash
+        )
+    });
+    ANIMATION_REGEX
+        .replace_all(&css, |captures: &Captures| {
+            let value = NAME_REGEX.replace_all(&captures[2], |name: &Captures| {
+                if names.contains(&name[0]) {
+                    format!("{}-{}", &name[0], hash)
+                } else {
+                    name[0].to_string()
+                }
+            });
+            format!("{}{}{}", &captures[1], value, &captures[3])
+        })
+        .into_owned()
+}
+
+/// Rewrites every style rule of `css` to only match elements carrying
+/// `attribute`. Comments are dropped and whitespace is collapsed.
+fn scope_rules(css: &str, attribute: &str) -> String {
+    let mut result = String::new();
+    let mut rest = css.trim();
+    while !rest.is_empty() {
+        let Some(end) = find_top_level(rest, &['{', ';']) else {
+            result.push_str(rest);
+            break;
+        };
+        let prelude = rest[..end].split_whitespace().collect::<Vec<_>>().join(" ");
+        if rest[end..].starts_with(';') {
+            result.push_str(&prelude);
+            result.push(';');
+            rest = rest[end + 1..].trim_start();
+            continue;
+        }
+        let close = matching_brace(rest, end);
+        let body = &rest[end + 1..close];
+        let at_rule = prelude
+            .strip_prefix('@')
+            .map(|rule| rule.split([' ', '(']).next().unwrap_or_default());
+        let body = match at_rule {
+            Some(name) if NESTED_AT_RULES.contains(&name) => scope_rules(body, attribute),
+            _ => body.split_whitespace().collect::<Vec<_>>().join(" "),
+        };
+        let prelude = if at_rule.is_some() {
+            prelude
+        } else {
+            let mut selectors = vec![];
+            let mut list = prelude.as_str();
+            while let Some(comma) = find_top_level(list, &[',']) {
+                selectors.push(scope_selector(&list[..comma], attribute));
+                list = &list[comma + 1..];
+            }
+            selectors.push(scope_selector(list, attribute));
+            selectors.join(",")
+        };
+        result.push_str(&format!("{}{{{}}}", prelude, body));
+        rest = rest[close + 1..].trim_start();
+    }
+    result
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Takes the `<style scoped>` elements out of the module. Their CSS is
+    /// scoped by an attribute derived from its hash, which every element
+    /// template of the file then carries.
+    pub fn collect_scoped_styles(&mut self, module: &mut Module) {
+        let mut collector = ScopedStyleCollector::default();
+        module.visit_mut_with(&mut collector);
+        if collector.css.is_empty() {
+            return;
+        }
+        let css = strip_comments(&collector.css.join("\n"));
+        let hash = hash(&css);
+        let attribute = format!("data-s-{}", hash);
+        self.scoped_style = Some(ScopedStyle {
+            css: scope_rules(&scope_keyframes(&css, &hash), &attribute),
+            attribute,
+        });
+    }
+
+    /// Adds the CSS to the document from a module-level IIFE, once per
+    /// attribute even when several modules share it, and not at all where
+    /// there's no document, as on the server:
+    ///
+    /// ```js
+    /// (() => {
+    ///   if (typeof document === "undefined" || document.head.querySelector("style[data-s-…]")) return;
+    ///   const style = document.createElement("style");
+    ///   style.setAttribute("data-s-…", "");
+    ///   style.textContent = css;
+    ///   document.head.appendChild(style);
+    /// })();
+    /// ```
+    pub fn insert_scoped_style(&mut self, module: &mut Module) {
+        let Some(style) = self.scoped_style.take() else {
+            return;
+        };
+        let str = |value: &str| Expr::Lit(Lit::Str(value.into()));
+        let call = |callee: Expr, args: Vec<Expr>| {
+            Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(callee)),
+                args: args.into_iter().map(ExprOrSpread::from).collect(),
+                type_args: None,
+            }))
+        };
+        let style_id = private_ident!("style");
+        let style_member = |name: &str| {
+            Expr::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: Box::new(Expr::Ident(style_id.clone())),
+                prop: MemberProp::Ident(quote_ident!(name)),
+            })
+        };
+        let expr_stmt = |expr: Box<Expr>| {
+            Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr,
+            })
+        };
+
+        let no_document = Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: BinaryOp::EqEqEq,
+            left: Box::new(Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: UnaryOp::TypeOf,
+                arg: Box::new(Expr::Ident(quote_ident!("document"))),
+            })),
+            right: Box::new(str("undefined")),
+        }));
+        let inserted = call(
+            member_path("document.head.querySelector"),
+            vec![str(&format!("style[{}]", style.attribute))],
+        );
+        let stmts = vec![
+            Stmt::If(IfStmt {
+                span: DUMMY_SP,
+                test: Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: BinaryOp::LogicalOr,
+                    left: no_document,
+                    right: inserted,
+                })),
+                cons: Box::new(Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: None,
+                })),
+                alt: None,
+            }),
+            Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(style_id.clone().into()),
+                    init: Some(call(
+                        member_path("document.createElement"),
+                        vec![str("style")],
+                    )),
+                    definite: false,
+                }],
+            }))),
+            expr_stmt(call(
+                style_member("setAttribute"),
+                vec![str(&style.attribute), str("")],
+            )),
+            expr_stmt(Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: AssignOp::Assign,
+                left: PatOrExpr::Expr(Box::new(style_member("textContent"))),
+                right: Box::new(str(&style.css)),
+            }))),
+            expr_stmt(call(
+                member_path("document.head.appendChild"),
+                vec![Expr::Ident(style_id.clone())],
+            )),
+        ];
+        let iife = call(
+            Expr::Paren(ParenExpr {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Arrow(ArrowExpr {
+                    span: DUMMY_SP,
+                    params: vec![],
+                    body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts,
+                    })),
+                    is_async: false,
+                    is_generator: false,
+                    type_params: None,
+                    return_type: None,
+                })),
+            }),
+            vec![],
+        );
+        prepend_stmt(&mut module.body, ModuleItem::Stmt(expr_stmt(iife)));
+    }
+}
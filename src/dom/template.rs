@@ -3,14 +3,90 @@ use crate::{
     shared::structs::{DynamicAttr, TemplateConstruction, TemplateInstantiation},
     TransformVisitor,
 };
+use std::collections::HashSet;
 use swc_core::{
     common::{comments::Comments, Span, DUMMY_SP},
     ecma::{
         ast::*,
         utils::{prepend_stmt, quote_ident},
+        visit::{Visit, VisitWith},
     },
 };
 
+/// Looks for code in a template instantiation that runs at definition time
+/// and does anything besides cloning a template and walking or assigning to
+/// its own elements. Runtime helpers like `insert` or `effect` evaluate user
+/// expressions, so any call other than the template's count. Functions are
+/// skipped as their bodies only run later.
+struct SideEffectVisitor {
+    locals: HashSet<Id>,
+    templates: HashSet<Id>,
+    found: bool,
+}
+
+impl SideEffectVisitor {
+    /// Whether `expr` is one of the template's elements or a member access on
+    /// one, like `_el$.style` or `_el$.$$click`.
+    fn is_local(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(id) => self.locals.contains(&id.to_id()),
+            Expr::Member(MemberExpr { obj, .. }) => self.is_local(obj),
+            _ => false,
+        }
+    }
+}
+
+impl Visit for SideEffectVisitor {
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        let local = match &n.left {
+            PatOrExpr::Expr(expr) => self.is_local(expr),
+            PatOrExpr::Pat(pat) => matches!(&**pat, Pat::Expr(expr) if self.is_local(expr)),
+        };
+        self.found |= !local;
+        n.right.visit_with(self);
+    }
+
+    fn visit_update_expr(&mut self, _: &UpdateExpr) {
+        self.found = true;
+    }
+
+    fn visit_new_expr(&mut self, _: &NewExpr) {
+        self.found = true;
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        let Callee::Expr(callee) = &n.callee else {
+            self.found = true;
+            return;
+        };
+        match unwrap_paren(callee) {
+            Expr::Ident(id) if self.templates.contains(&id.to_id()) => {}
+            // IIFEs run right away, so their bodies count.
+            Expr::Arrow(arrow) if n.args.is_empty() => arrow.body.visit_with(self),
+            _ => self.found = true,
+        }
+        n.args.visit_with(self);
+    }
+
+    /// Reading a property of anything but the template's elements may run a
+    /// user getter.
+    fn visit_member_expr(&mut self, n: &MemberExpr) {
+        self.found |= !self.is_local(&n.obj);
+        n.prop.visit_with(self);
+    }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(ParenExpr { expr, .. }) => unwrap_paren(expr),
+        expr => expr,
+    }
+}
+
 impl<C> TransformVisitor<C>
 where
     C: Comments,
@@ -18,15 +94,23 @@ where
     pub fn create_template(&mut self, result: &mut TemplateInstantiation, wrap: bool) -> Expr {
         if let Some(id) = result.id.clone() {
             self.register_template(result);
+            if self.config.optimize_walks {
+                self.optimize_walks(result);
+            }
             if result.exprs.is_empty()
                 && result.dynamics.is_empty()
                 && result.post_exprs.is_empty()
                 && result.declarations.len() == 1
             {
-                return *result.declarations[0].init.clone().unwrap();
+                return *result.declarations.remove(0).init.unwrap();
             } else {
+                let span = if self.has_side_effects(result) {
+                    DUMMY_SP
+                } else {
+                    self.pure_span()
+                };
                 return Expr::Call(CallExpr {
-                    span: DUMMY_SP,
+                    span,
                     callee: Callee::Expr(Box::new(Expr::Arrow(ArrowExpr {
                         span: DUMMY_SP,
                         params: vec![],
@@ -36,10 +120,10 @@ where
                                 span: DUMMY_SP,
                                 kind: VarDeclKind::Const,
                                 declare: false,
-                                decls: result.declarations.clone(),
+                                decls: std::mem::take(&mut result.declarations),
                             })))]
                             .into_iter()
-                            .chain(result.exprs.clone().into_iter().map(|x| {
+                            .chain(std::mem::take(&mut result.exprs).into_iter().map(|x| {
                                 Stmt::Expr(ExprStmt {
                                     span: DUMMY_SP,
                                     expr: Box::new(x),
@@ -56,7 +140,7 @@ where
                                         })
                                     }),
                             )
-                            .chain(result.post_exprs.clone().into_iter().map(|x| {
+                            .chain(std::mem::take(&mut result.post_exprs).into_iter().map(|x| {
                                 Stmt::Expr(ExprStmt {
                                     span: DUMMY_SP,
                                     expr: Box::new(x),
@@ -85,12 +169,41 @@ where
                 callee: Callee::Expr(Box::new(Expr::Ident(
                     self.register_import_method(&self.config.memo_wrapper.clone()),
                 ))),
-                args: vec![result.exprs[0].clone().into()],
+                args: vec![result.exprs.swap_remove(0).into()],
                 type_args: None,
             });
         }
 
-        result.exprs[0].clone()
+        result.exprs.swap_remove(0)
+    }
+
+    /// Whether instantiating `result` does more than clone and walk its own
+    /// elements, e.g. inserting children, running effects or assigning refs,
+    /// in which case its IIFE can't be marked pure.
+    fn has_side_effects(&self, result: &TemplateInstantiation) -> bool {
+        if !result.dynamics.is_empty() {
+            return true;
+        }
+        let mut visitor = SideEffectVisitor {
+            locals: result
+                .declarations
+                .iter()
+                .filter_map(|decl| decl.name.as_ident().map(|id| id.to_id()))
+                .collect(),
+            templates: self
+                .templates
+                .iter()
+                .map(|template| template.id.to_id())
+                .collect(),
+            found: false,
+        };
+        for decl in &result.declarations {
+            decl.init.visit_with(&mut visitor);
+        }
+        for expr in result.exprs.iter().chain(&result.post_exprs) {
+            expr.visit_with(&mut visitor);
+        }
+        visitor.found
     }
 
     pub fn append_templates(&mut self, module: &mut Module) {
@@ -98,18 +211,16 @@ where
             return;
         }
         let templ = self.register_import_method("template");
+        let templates = std::mem::take(&mut self.templates);
         prepend_stmt(
             &mut module.body,
             ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
                 span: DUMMY_SP,
                 kind: VarDeclKind::Const,
                 declare: false,
-                decls: self
-                    .templates
-                    .drain(..)
+                decls: templates
+                    .into_iter()
                     .map(|template| {
-                        let span = Span::dummy_with_cmt();
-                        self.comments.add_pure_comment(span.lo);
                         let mut args = vec![ExprOrSpread {
                             spread: None,
                             expr: Box::new(
@@ -136,15 +247,25 @@ where
                                 expr: Box::new(Expr::Lit(template.is_svg.into())),
                             });
                         }
+                        let span = self.pure_span();
+                        let call = Expr::Call(CallExpr {
+                            span: if self.config.lazy_templates {
+                                DUMMY_SP
+                            } else {
+                                span
+                            },
+                            callee: Callee::Expr(Box::new(Expr::Ident(templ.clone()))),
+                            args,
+                            type_args: None,
+                        });
                         VarDeclarator {
                             span: DUMMY_SP,
                             name: template.id.into(),
-                            init: Some(Box::new(Expr::Call(CallExpr {
-                                span,
-                                callee: Callee::Expr(Box::new(Expr::Ident(templ.clone()))),
-                                args,
-                                type_args: None,
-                            }))),
+                            init: Some(Box::new(if self.config.lazy_templates {
+                                lazy_template(call, span)
+                            } else {
+                                call
+                            })),
                             definite: false,
                         }
                     })
@@ -386,13 +507,10 @@ where
                             })))]
                             .into_iter()
                             .chain(statements)
-                            .chain(
-                                [Stmt::Return(ReturnStmt {
-                                    span: Default::default(),
-                                    arg: Some(Box::new(Expr::Ident(prev_id))),
-                                })]
-                                .into_iter(),
-                            )
+                            .chain([Stmt::Return(ReturnStmt {
+                                span: Default::default(),
+                                arg: Some(Box::new(Expr::Ident(prev_id))),
+                            })])
                             .collect(),
                         })),
                         is_async: false,
@@ -421,3 +539,76 @@ where
         })])
     }
 }
+
+/// Wraps a `template()` call in a getter that only creates the template the
+/// first time it is instantiated:
+/// `(() => { let t; return () => (t || (t = template(...)))(); })()`.
+fn lazy_template(call: Expr, span: Span) -> Expr {
+    let cache = quote_ident!("t");
+    let getter = Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        params: vec![],
+        body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Paren(ParenExpr {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: BinaryOp::LogicalOr,
+                    left: Box::new(Expr::Ident(cache.clone())),
+                    right: Box::new(Expr::Paren(ParenExpr {
+                        span: DUMMY_SP,
+                        expr: Box::new(Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            op: AssignOp::Assign,
+                            left: PatOrExpr::Pat(Box::new(Pat::Ident(cache.clone().into()))),
+                            right: Box::new(call),
+                        })),
+                    })),
+                })),
+            }))),
+            args: vec![],
+            type_args: None,
+        })))),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    });
+    Expr::Call(CallExpr {
+        span,
+        callee: Callee::Expr(Box::new(Expr::Paren(ParenExpr {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Arrow(ArrowExpr {
+                span: DUMMY_SP,
+                params: vec![],
+                body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![
+                        Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Let,
+                            declare: false,
+                            decls: vec![VarDeclarator {
+                                span: DUMMY_SP,
+                                name: Pat::Ident(cache.into()),
+                                init: None,
+                                definite: false,
+                            }],
+                        }))),
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(Box::new(getter)),
+                        }),
+                    ],
+                })),
+                is_async: false,
+                is_generator: false,
+                type_params: None,
+                return_type: None,
+            })),
+        }))),
+        args: vec![],
+        type_args: None,
+    })
+}
@@ -0,0 +1,287 @@
+use crate::{
+    shared::{constants::VOID_ELEMENTS, structs::TemplateInstantiation},
+    TransformVisitor,
+};
+use std::collections::{HashMap, HashSet};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+const RAW_TEXT_ELEMENTS: [&str; 4] = ["script", "style", "textarea", "title"];
+
+/// Child counts of the nodes of a template, indexed like the DOM the browser
+/// builds from it. Node `0` is the template content itself.
+struct TemplateTree {
+    children: Vec<Vec<usize>>,
+}
+
+impl TemplateTree {
+    fn parse(template: &str) -> Self {
+        let mut tree = TemplateTree {
+            children: vec![vec![]],
+        };
+        let mut stack: Vec<(usize, String)> = vec![(0, String::new())];
+        let mut rest = template;
+
+        while !rest.is_empty() {
+            let parent = stack.last().unwrap().0;
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                tree.add(parent);
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            } else if let Some(comment) = rest.strip_prefix("<!") {
+                tree.add(parent);
+                rest = comment.find('>').map_or("", |end| &comment[end + 1..]);
+            } else if let Some(close) = rest.strip_prefix("</") {
+                let end = close.find('>').unwrap_or(close.len());
+                let name = close[..end].trim().to_ascii_lowercase();
+                if let Some(index) = stack.iter().rposition(|(_, tag)| *tag == name) {
+                    stack.truncate(index.max(1));
+                }
+                rest = close.get(end + 1..).unwrap_or("");
+            } else if rest.starts_with('<') {
+                let name_end = rest[1..]
+                    .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                    .map_or(rest.len(), |end| end + 1);
+                let name = rest[1..name_end].to_ascii_lowercase();
+                let (tag_end, self_closing) = find_tag_end(rest);
+                let node = tree.add(parent);
+                rest = &rest[tag_end..];
+                if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                    let end = rest.find("</").unwrap_or(rest.len());
+                    if end > 0 {
+                        tree.add(node);
+                    }
+                    rest = &rest[end..];
+                    rest = rest.find('>').map_or("", |close| &rest[close + 1..]);
+                } else if !self_closing && !VOID_ELEMENTS.contains(&name.as_str()) {
+                    stack.push((node, name));
+                }
+            } else {
+                tree.add(parent);
+                rest = rest.find('<').map_or("", |end| &rest[end..]);
+            }
+        }
+        tree
+    }
+
+    fn add(&mut self, parent: usize) -> usize {
+        let node = self.children.len();
+        self.children.push(vec![]);
+        self.children[parent].push(node);
+        node
+    }
+
+    /// Resolves a path of child indexes starting at the template's root
+    /// element.
+    fn resolve(&self, path: &[usize]) -> Option<usize> {
+        path.iter()
+            .try_fold(*self.children[0].first()?, |node, index| {
+                self.children[node].get(*index).copied()
+            })
+    }
+}
+
+/// Returns the byte offset just past the `>` closing the tag at the start of
+/// `tag`, skipping over quoted attribute values, and whether it self-closes.
+fn find_tag_end(tag: &str) -> (usize, bool) {
+    let mut quote = None;
+    for (index, c) in tag.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if q == c => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return (index + 1, tag[..index].ends_with('/')),
+            _ => {}
+        }
+    }
+    (tag.len(), false)
+}
+
+#[derive(Default)]
+struct IdentCollector {
+    ids: HashSet<Id>,
+}
+
+impl Visit for IdentCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.ids.insert(ident.to_id());
+    }
+}
+
+fn walk_step(expr: &Expr) -> Option<(Id, &str)> {
+    match expr {
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => match (&**obj, &*prop.sym) {
+            (Expr::Ident(obj), prop @ ("firstChild" | "nextSibling")) => Some((obj.to_id(), prop)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn member(obj: Expr, prop: &str) -> Expr {
+    Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(obj),
+        prop: MemberProp::Ident(Ident::new(prop.into(), DUMMY_SP)),
+    })
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Rewrites the `firstChild`/`nextSibling` walk declarations of a
+    /// registered template. Only nodes that are referenced, or that branch
+    /// towards several referenced nodes, keep a variable, and each one is
+    /// reached from the closest declared node using `lastChild`/
+    /// `previousSibling` when that takes fewer reads.
+    pub fn optimize_walks(&self, results: &mut TemplateInstantiation) {
+        let Some(root) = &results.id else { return };
+        let Some(Pat::Ident(first)) = results.declarations.first().map(|decl| &decl.name) else {
+            return;
+        };
+        if first.to_id() != root.to_id() {
+            return;
+        }
+
+        let tree = TemplateTree::parse(&results.template);
+        let mut paths: HashMap<Id, Vec<usize>> = HashMap::from([(root.to_id(), vec![])]);
+        let mut walks = vec![];
+        let mut used = IdentCollector::default();
+        for decl in results.declarations.iter().skip(1) {
+            let step = decl.init.as_deref().and_then(walk_step);
+            match (&decl.name, step) {
+                (Pat::Ident(id), Some((obj, prop))) if paths.contains_key(&obj) => {
+                    let mut path = paths[&obj].clone();
+                    if prop == "firstChild" {
+                        path.push(0);
+                    } else if let Some(last) = path.last_mut() {
+                        *last += 1;
+                    } else {
+                        return;
+                    }
+                    if tree.resolve(&path).is_none() {
+                        return;
+                    }
+                    paths.insert(id.to_id(), path);
+                    walks.push(id.to_id());
+                }
+                _ => decl.init.visit_with(&mut used),
+            }
+        }
+        for expr in results.exprs.iter().chain(&results.post_exprs) {
+            expr.visit_with(&mut used);
+        }
+        for dynamic in &results.dynamics {
+            used.ids.insert(dynamic.elem.to_id());
+            dynamic.value.visit_with(&mut used);
+        }
+
+        let targets: Vec<&Vec<usize>> = walks
+            .iter()
+            .filter(|id| used.ids.contains(id))
+            .map(|id| &paths[id])
+            .collect();
+        let keep: HashSet<Id> = walks
+            .iter()
+            .filter(|id| {
+                let path = &paths[*id];
+                let branches: HashSet<usize> = targets
+                    .iter()
+                    .filter(|target| target.len() > path.len() && target.starts_with(path))
+                    .map(|target| target[path.len()])
+                    .collect();
+                used.ids.contains(id) || branches.len() > 1
+            })
+            .cloned()
+            .collect();
+
+        let mut declarations = std::mem::take(&mut results.declarations).into_iter();
+        let mut declared = vec![(root.clone(), vec![])];
+        results.declarations.extend(declarations.next());
+        for decl in declarations {
+            let ident = match &decl.name {
+                Pat::Ident(ident) if paths.contains_key(&ident.to_id()) => ident.id.clone(),
+                _ => {
+                    results.declarations.push(decl);
+                    continue;
+                }
+            };
+            if !keep.contains(&ident.to_id()) {
+                continue;
+            }
+            let path = paths[&ident.to_id()].clone();
+            let init = declared
+                .iter()
+                .rev()
+                .filter_map(|(base, base_path)| walk_from(&tree, base, base_path, &path))
+                .min_by_key(|(cost, _)| *cost)
+                .map(|(_, init)| init);
+            results.declarations.push(VarDeclarator {
+                init: init.map(Box::new).or(decl.init),
+                ..decl
+            });
+            declared.push((ident, path));
+        }
+    }
+}
+
+/// Builds the cheapest access from `base` to the node at `path`, when
+/// `base` is an ancestor of it or a sibling of one of its ancestors, along
+/// with the number of property reads it takes.
+fn walk_from(
+    tree: &TemplateTree,
+    base: &Ident,
+    base_path: &[usize],
+    path: &[usize],
+) -> Option<(usize, Expr)> {
+    let mut expr = Expr::Ident(base.clone());
+    let mut cost = 0;
+    let depth = if path.starts_with(base_path) {
+        base_path.len()
+    } else {
+        let (index, parent) = base_path.split_last()?;
+        if !path.starts_with(parent) || path.len() == parent.len() {
+            return None;
+        }
+        let target = path[parent.len()];
+        let prop = if target > *index {
+            "nextSibling"
+        } else {
+            "previousSibling"
+        };
+        for _ in 0..target.abs_diff(*index) {
+            expr = member(expr, prop);
+            cost += 1;
+        }
+        base_path.len()
+    };
+
+    for level in depth..path.len() {
+        let index = path[level];
+        let count = tree.children[tree.resolve(&path[..level])?].len();
+        let from_end = count - index - 1;
+        if index <= from_end {
+            expr = member(expr, "firstChild");
+            for _ in 0..index {
+                expr = member(expr, "nextSibling");
+            }
+            cost += index + 1;
+        } else {
+            expr = member(expr, "lastChild");
+            for _ in 0..from_end {
+                expr = member(expr, "previousSibling");
+            }
+            cost += from_end + 1;
+        }
+    }
+    Some((cost, expr))
+}
@@ -1,9 +1,8 @@
-use shared::transform::ThisBlockVisitor;
+use shared::{html::HtmlParser, transform::LexicalCaptureVisitor, utils::emit_error};
 use swc_core::{
-    common::{comments::Comments, util::take::Take},
+    common::{comments::Comments, sync::Lrc, util::take::Take},
     ecma::{
         ast::*,
-        minifier::{eval::Evaluator, marks::Marks},
         visit::{as_folder, FoldWith, VisitMut, VisitMutWith, VisitWith},
     },
     plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
@@ -31,30 +30,45 @@ where
                     _ => {}
                 };
             }
+            Expr::TaggedTpl(TaggedTpl { tag, tpl, span, .. }) if self.is_html_tag(tag) => {
+                match HtmlParser::new(tpl, *span).parse() {
+                    Ok(node) => *expr = self.transform_jsx(&node),
+                    Err(error) => emit_error(*span, &format!("html template: {}", error)),
+                }
+            }
             _ => {}
         };
         expr.visit_mut_children_with(self);
     }
     fn visit_mut_module(&mut self, module: &mut Module) {
-        module.visit_mut_children_with(&mut ThisBlockVisitor::new());
-        self.evaluator = Some(Evaluator::new(module.clone(), Marks::new()));
-        module.visit_children_with(&mut self.binding_collector);
+        self.apply_config_pragmas(module);
+        if self.config.transform_destructured_props {
+            self.transform_destructured_props(module);
+        }
+        self.binding_collector.module_name = self.config.module_name.clone();
+        module.visit_with(&mut self.binding_collector);
+        module.visit_mut_with(&mut LexicalCaptureVisitor::new(self));
+        self.collect_scoped_styles(module);
         module.visit_mut_children_with(self);
 
+        self.insert_scoped_style(module);
+        self.insert_hoisted_handlers(module);
         self.append_templates(module);
         self.insert_events(module);
+        self.insert_asset_imports(module);
         self.insert_imports(module);
     }
 }
 
 #[plugin_transform]
 pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
-    let config: config::Config = metadata
-        .get_transform_plugin_config()
-        .and_then(|json| serde_json::from_str(&json).ok())
-        .unwrap_or_default();
-    program.fold_with(&mut as_folder(TransformVisitor::new(
-        config,
-        &metadata.comments,
-    )))
+    let config = match metadata.get_transform_plugin_config() {
+        Some(json) => config::Config::from_json(&json)
+            .unwrap_or_else(|error| panic!("Invalid jsx-dom-expressions options: {}", error)),
+        None => Default::default(),
+    };
+    program.fold_with(&mut as_folder(
+        TransformVisitor::new(config, &metadata.comments, metadata.unresolved_mark)
+            .with_source_map(Lrc::new(metadata.source_map.clone())),
+    ))
 }
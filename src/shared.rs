@@ -1,6 +1,11 @@
 pub mod component;
 pub mod constants;
+pub mod evaluate;
 pub mod fragment;
+pub mod html;
+pub mod map_children;
+pub mod props;
+pub mod signals;
 pub mod structs;
 pub mod transform;
 pub mod utils;
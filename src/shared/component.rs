@@ -1,9 +1,9 @@
 use super::{
     structs::TemplateInstantiation,
     transform::TransformInfo,
-    utils::{convert_jsx_identifier, filter_children, jsx_text_to_str},
+    utils::{convert_jsx_identifier, emit_warning, filter_children, text_to_str},
 };
-use crate::{shared::utils::is_l_val, TransformVisitor};
+use crate::{config::Generate, shared::utils::is_l_val, TransformVisitor};
 use swc_core::{
     common::{comments::Comments, DUMMY_SP},
     ecma::{ast::*, utils::quote_ident},
@@ -23,12 +23,14 @@ where
 
         if let Expr::Ident(id) = &tag_id {
             if self.config.built_ins.iter().any(|v| v.as_str() == &id.sym)
-                && id.span.ctxt.as_u32() == 1
+                && id.span.ctxt.outer() == self.unresolved_mark
             {
                 tag_id = Expr::Ident(self.register_import_method(&id.sym));
             }
         }
 
+        // components may take accessors as props, so only children are checked
+        self.warn_uncalled_signals_in_component_children(&node.children);
         for attribute in &node.opening.attrs {
             match attribute {
                 JSXAttrOrSpread::SpreadElement(node) => {
@@ -81,6 +83,14 @@ where
                         continue;
                     }
 
+                    if self.config.react_compat && key == "key" {
+                        emit_warning(
+                            attr.span,
+                            "`key` has no effect outside of React and was removed",
+                        );
+                        continue;
+                    }
+
                     match attr.value.clone() {
                         Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
                             expr: JSXExpr::Expr(expr),
@@ -453,7 +463,7 @@ where
 
         exprs.push(
             CallExpr {
-                span: DUMMY_SP,
+                span: self.pure_span(),
                 callee: Callee::Expr(self.register_import_method("createComponent").into()),
                 args: component_args
                     .into_iter()
@@ -520,9 +530,17 @@ where
         &mut self,
         children: &[JSXElementChild],
     ) -> Option<(Expr, bool)> {
+        let mapped;
+        let children = if self.config.map_children_to_for {
+            mapped = self.map_children_to_for(children);
+            &mapped[..]
+        } else {
+            children
+        };
+        let whitespace = self.config.whitespace;
         let filtered_children = children
             .iter()
-            .filter(|child| filter_children(child))
+            .filter(|child| filter_children(child, whitespace))
             .collect::<Vec<_>>();
         if filtered_children.is_empty() {
             return None;
@@ -536,7 +554,7 @@ where
             filtered_children.iter().fold(vec![], |mut memo, node| {
                 match node {
                     JSXElementChild::JSXText(child) => {
-                        let value = jsx_text_to_str(&child.value);
+                        let value = text_to_str(&self.jsx_text_value(child), whitespace);
                         if value.len() > 0 {
                             path_nodes.push(node);
                             memo.push(Lit::Str(value.into()).into());
@@ -555,7 +573,7 @@ where
                         if let Some(mut child) = child {
                             dynamic = dynamic || child.dynamic;
 
-                            if self.config.generate == "ssr"
+                            if self.config.generate == Generate::Ssr
                                 && is_filtered_children_plural
                                 && child.dynamic
                             {
@@ -646,6 +664,9 @@ where
 
 fn get_component_identifier(node: &JSXElementName) -> Expr {
     match node {
+        JSXElementName::Ident(ident) if &ident.sym == "this" => {
+            Expr::This(ThisExpr { span: DUMMY_SP })
+        }
         JSXElementName::Ident(ident) => match Ident::verify_symbol(&ident.sym) {
             Ok(_) => Expr::Ident(ident.clone()),
             Err(_) => Expr::Lit(Lit::Str(ident.sym.to_string().into())),
@@ -0,0 +1,276 @@
+use crate::{shared::utils::number_to_string, TransformVisitor};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::ast::*,
+};
+
+/// How many binding references are followed before giving up, so cyclic or
+/// very deep chains of bindings can't blow the stack.
+const MAX_DEPTH: usize = 32;
+
+/// The decimal literals `Number("…")` accepts, once trimmed.
+static DECIMAL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?$").unwrap());
+
+/// Whether `expr` only consists of what [TransformVisitor::evaluate] may
+/// fold, so it's worth keeping as the initializer of a binding.
+pub fn is_foldable(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(lit) => matches!(lit, Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_)),
+        Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| is_foldable(expr)),
+        Expr::Ident(_) => true,
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+        | Expr::Unary(UnaryExpr { arg: expr, .. }) => is_foldable(expr),
+        Expr::Bin(BinExpr { left, right, .. }) => is_foldable(left) && is_foldable(right),
+        Expr::Cond(CondExpr {
+            test, cons, alt, ..
+        }) => is_foldable(test) && is_foldable(cons) && is_foldable(alt),
+        _ => false,
+    }
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Folds `expr` to a literal when it only consists of literals, template
+    /// literals, unary/binary/conditional operators and references to bindings
+    /// that are never reassigned and whose initializer folds as well.
+    pub fn evaluate(&self, expr: &Expr) -> Option<Lit> {
+        self.evaluate_expr(expr, 0)
+    }
+
+    fn evaluate_expr(&self, expr: &Expr, depth: usize) -> Option<Lit> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match expr {
+            Expr::Lit(lit) => match lit {
+                Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_) => Some(lit.clone()),
+                _ => None,
+            },
+            Expr::Tpl(tpl) => {
+                let mut value = String::new();
+                for (index, quasi) in tpl.quasis.iter().enumerate() {
+                    value.push_str(quasi.cooked.as_deref()?);
+                    if let Some(expr) = tpl.exprs.get(index) {
+                        value.push_str(&to_js_string(&self.evaluate_expr(expr, depth)?));
+                    }
+                }
+                Some(str_lit(value))
+            }
+            Expr::Ident(id) => {
+                let init = self.binding_collector.constant_init(&id.to_id())?;
+                self.evaluate_expr(init, depth + 1)
+            }
+            Expr::Paren(ParenExpr { expr, .. })
+            | Expr::TsAs(TsAsExpr { expr, .. })
+            | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+            | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+            | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+            | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => {
+                self.evaluate_expr(expr, depth)
+            }
+            Expr::Unary(UnaryExpr { op, arg, .. }) => {
+                let arg = self.evaluate_expr(arg, depth)?;
+                match op {
+                    UnaryOp::Bang => Some(bool_lit(!is_truthy(&arg))),
+                    UnaryOp::Minus => Some(num_lit(-to_number(&arg)?)),
+                    UnaryOp::Plus => Some(num_lit(to_number(&arg)?)),
+                    UnaryOp::Tilde => Some(num_lit(!to_int32(to_number(&arg)?) as f64)),
+                    UnaryOp::TypeOf => Some(str_lit(
+                        match arg {
+                            Lit::Str(_) => "string",
+                            Lit::Num(_) => "number",
+                            Lit::Bool(_) => "boolean",
+                            _ => "object",
+                        }
+                        .to_string(),
+                    )),
+                    _ => None,
+                }
+            }
+            Expr::Bin(BinExpr {
+                op, left, right, ..
+            }) => {
+                let left = self.evaluate_expr(left, depth)?;
+                match op {
+                    BinaryOp::LogicalAnd if !is_truthy(&left) => return Some(left),
+                    BinaryOp::LogicalOr if is_truthy(&left) => return Some(left),
+                    BinaryOp::NullishCoalescing if !matches!(left, Lit::Null(_)) => {
+                        return Some(left)
+                    }
+                    BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+                        return self.evaluate_expr(right, depth)
+                    }
+                    _ => {}
+                }
+                let right = self.evaluate_expr(right, depth)?;
+                evaluate_binary(*op, &left, &right)
+            }
+            Expr::Cond(CondExpr {
+                test, cons, alt, ..
+            }) => {
+                if is_truthy(&self.evaluate_expr(test, depth)?) {
+                    self.evaluate_expr(cons, depth)
+                } else {
+                    self.evaluate_expr(alt, depth)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+fn evaluate_binary(op: BinaryOp, left: &Lit, right: &Lit) -> Option<Lit> {
+    let numbers = || Some((to_number(left)?, to_number(right)?));
+    Some(match op {
+        BinaryOp::Add => {
+            if matches!(left, Lit::Str(_)) || matches!(right, Lit::Str(_)) {
+                str_lit(format!("{}{}", to_js_string(left), to_js_string(right)))
+            } else {
+                let (l, r) = numbers()?;
+                num_lit(l + r)
+            }
+        }
+        BinaryOp::Sub => numbers().map(|(l, r)| num_lit(l - r))?,
+        BinaryOp::Mul => numbers().map(|(l, r)| num_lit(l * r))?,
+        BinaryOp::Div => numbers().map(|(l, r)| num_lit(l / r))?,
+        BinaryOp::Mod => numbers().map(|(l, r)| num_lit(l % r))?,
+        BinaryOp::Exp => numbers().map(|(l, r)| num_lit(l.powf(r)))?,
+        BinaryOp::EqEqEq => bool_lit(strict_equals(left, right)),
+        BinaryOp::NotEqEq => bool_lit(!strict_equals(left, right)),
+        BinaryOp::Lt | BinaryOp::LtEq | BinaryOp::Gt | BinaryOp::GtEq => {
+            let ordering = match (left, right) {
+                (Lit::Str(l), Lit::Str(r)) => l.value.partial_cmp(&r.value),
+                _ => numbers().and_then(|(l, r)| l.partial_cmp(&r)),
+            };
+            bool_lit(ordering.map_or(false, |ordering| match op {
+                BinaryOp::Lt => ordering.is_lt(),
+                BinaryOp::LtEq => ordering.is_le(),
+                BinaryOp::Gt => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            }))
+        }
+        _ => return None,
+    })
+}
+
+fn strict_equals(left: &Lit, right: &Lit) -> bool {
+    match (left, right) {
+        (Lit::Str(l), Lit::Str(r)) => l.value == r.value,
+        (Lit::Num(l), Lit::Num(r)) => l.value == r.value,
+        (Lit::Bool(l), Lit::Bool(r)) => l.value == r.value,
+        (Lit::Null(_), Lit::Null(_)) => true,
+        _ => false,
+    }
+}
+
+pub fn is_truthy(lit: &Lit) -> bool {
+    match lit {
+        Lit::Str(value) => !value.value.is_empty(),
+        Lit::Num(value) => value.value != 0.0 && !value.value.is_nan(),
+        Lit::Bool(value) => value.value,
+        Lit::Null(_) => false,
+        _ => true,
+    }
+}
+
+fn to_number(lit: &Lit) -> Option<f64> {
+    match lit {
+        Lit::Num(value) => Some(value.value),
+        Lit::Bool(value) => Some(if value.value { 1.0 } else { 0.0 }),
+        Lit::Null(_) => Some(0.0),
+        Lit::Str(value) => Some(string_to_number(&value.value)),
+        _ => None,
+    }
+}
+
+/// JS `Number(text)`: decimal, hexadecimal, octal and binary literals and
+/// `Infinity`, surrounded by any whitespace.
+fn string_to_number(text: &str) -> f64 {
+    let text = text.trim_matches(|c: char| {
+        matches!(
+            c,
+            '\t' | '\n' | '\u{b}' | '\u{c}' | '\r' | ' ' | '\u{a0}' | '\u{1680}' | '\u{2000}'
+                ..='\u{200a}'
+                    | '\u{2028}'
+                    | '\u{2029}'
+                    | '\u{202f}'
+                    | '\u{205f}'
+                    | '\u{3000}'
+                    | '\u{feff}'
+        )
+    });
+    if text.is_empty() {
+        return 0.0;
+    }
+    let radix = match text.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        let digits = &text[2..];
+        if digits.is_empty() {
+            return f64::NAN;
+        }
+        return digits
+            .chars()
+            .try_fold(0.0, |value, c| {
+                c.to_digit(radix)
+                    .map(|digit| value * radix as f64 + digit as f64)
+            })
+            .unwrap_or(f64::NAN);
+    }
+    match text {
+        "Infinity" | "+Infinity" => f64::INFINITY,
+        "-Infinity" => f64::NEG_INFINITY,
+        _ if DECIMAL_REGEX.is_match(text) => text.parse().unwrap_or(f64::NAN),
+        _ => f64::NAN,
+    }
+}
+
+/// JS ToInt32: the number truncated and wrapped to a signed 32-bit integer.
+fn to_int32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    (value.trunc() % 4294967296.0) as i64 as u32 as i32
+}
+
+fn to_js_string(lit: &Lit) -> String {
+    match lit {
+        Lit::Str(value) => value.value.to_string(),
+        Lit::Num(value) => number_to_string(value.value),
+        Lit::Bool(value) => value.value.to_string(),
+        _ => "null".to_string(),
+    }
+}
+
+fn str_lit(value: String) -> Lit {
+    Lit::Str(value.into())
+}
+
+fn num_lit(value: f64) -> Lit {
+    Lit::Num(Number {
+        span: DUMMY_SP,
+        value,
+        raw: None,
+    })
+}
+
+fn bool_lit(value: bool) -> Lit {
+    Lit::Bool(Bool {
+        span: DUMMY_SP,
+        value,
+    })
+}
@@ -1,7 +1,7 @@
 use super::{
     structs::TemplateInstantiation,
     transform::TransformInfo,
-    utils::{filter_children, jsx_text_to_str},
+    utils::{filter_children, text_to_str},
 };
 pub use crate::shared::structs::TransformVisitor;
 use swc_core::{
@@ -32,28 +32,28 @@ where
         children: &[JSXElementChild],
         results: &mut TemplateInstantiation,
     ) {
-        let child_nodes: Vec<Expr> =
-            children
-                .iter()
-                .filter(|c| filter_children(c))
-                .fold(vec![], |mut memo, node| {
-                    match node {
-                        JSXElementChild::JSXText(child) => {
-                            let value = jsx_text_to_str(&child.value);
-                            if value.len() > 0 {
-                                memo.push(Expr::Lit(Lit::Str(value.into())))
-                            }
+        let whitespace = self.config.whitespace;
+        let child_nodes: Vec<Expr> = children
+            .iter()
+            .filter(|c| filter_children(c, whitespace))
+            .fold(vec![], |mut memo, node| {
+                match node {
+                    JSXElementChild::JSXText(child) => {
+                        let value = text_to_str(&self.jsx_text_value(child), whitespace);
+                        if value.len() > 0 {
+                            memo.push(Expr::Lit(Lit::Str(value.into())))
+                        }
+                    }
+                    JSXElementChild::JSXExprContainer(child) => match &child.expr {
+                        JSXExpr::Expr(new_expr) if new_expr.is_lit() || new_expr.is_ident() => {
+                            memo.push(*new_expr.clone())
                         }
-                        JSXElementChild::JSXExprContainer(child) => match &child.expr {
-                            JSXExpr::Expr(new_expr) if new_expr.is_lit() || new_expr.is_ident() => {
-                                memo.push(*new_expr.clone())
-                            }
-                            _ => memo.push(do_default(self, node)),
-                        },
                         _ => memo.push(do_default(self, node)),
-                    };
-                    memo
-                });
+                    },
+                    _ => memo.push(do_default(self, node)),
+                };
+                memo
+            });
 
         if child_nodes.len() == 1 {
             results.exprs.push(child_nodes[0].clone())
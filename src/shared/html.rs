@@ -0,0 +1,436 @@
+use swc_core::{
+    common::{comments::Comments, Span, Spanned, DUMMY_SP},
+    ecma::{ast::*, utils::quote_ident},
+};
+
+use super::constants::VOID_ELEMENTS;
+use crate::TransformVisitor;
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Whether `tag` is the configured `html` tag, either a global or an
+    /// import, rather than a local binding that shadows it.
+    pub fn is_html_tag(&self, tag: &Expr) -> bool {
+        match tag {
+            Expr::Ident(id) if !self.config.html_tag.is_empty() => {
+                *id.sym == self.config.html_tag
+                    && (id.span.ctxt.outer() == self.unresolved_mark
+                        || self.binding_collector.import_bindings.contains(&id.to_id()))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A character of the template's static text or one of its `${}` holes.
+#[derive(Clone, Copy, PartialEq)]
+enum Piece {
+    Char(char),
+    Hole(usize),
+}
+
+/// Parses the quasis and holes of an `html` tagged template into the JSX
+/// tree the equivalent JSX source would produce, so it goes through the same
+/// element and component transforms. Malformed templates are reported with
+/// a message describing the problem.
+pub struct HtmlParser<'a> {
+    pieces: Vec<Piece>,
+    exprs: &'a [Box<Expr>],
+    pos: usize,
+    span: Span,
+}
+
+impl<'a> HtmlParser<'a> {
+    pub fn new(tpl: &'a Tpl, span: Span) -> Self {
+        let mut pieces = vec![];
+        for (index, quasi) in tpl.quasis.iter().enumerate() {
+            let text = quasi.cooked.as_ref().unwrap_or(&quasi.raw);
+            pieces.extend(text.chars().map(Piece::Char));
+            if index < tpl.exprs.len() {
+                pieces.push(Piece::Hole(index));
+            }
+        }
+        Self {
+            pieces,
+            exprs: &tpl.exprs,
+            pos: 0,
+            span,
+        }
+    }
+
+    /// Returns the single root element, or a fragment when the template has
+    /// several top level nodes.
+    pub fn parse(mut self) -> Result<JSXElementChild, String> {
+        let mut children = self.parse_children(None)?;
+        let roots: Vec<_> = children
+            .iter()
+            .filter(|child| match child {
+                JSXElementChild::JSXText(text) => !text.value.trim().is_empty(),
+                _ => true,
+            })
+            .collect();
+        if roots.len() == 1 && matches!(roots[0], JSXElementChild::JSXElement(_)) {
+            let index = children
+                .iter()
+                .position(|child| matches!(child, JSXElementChild::JSXElement(_)))
+                .unwrap();
+            return Ok(children.swap_remove(index));
+        }
+        Ok(JSXElementChild::JSXFragment(JSXFragment {
+            span: self.span,
+            opening: JSXOpeningFragment { span: self.span },
+            children,
+            closing: JSXClosingFragment { span: self.span },
+        }))
+    }
+
+    fn peek(&self) -> Option<Piece> {
+        self.pieces.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(i, c)| self.pieces.get(self.pos + i) == Some(&Piece::Char(c)))
+    }
+
+    fn eat(&mut self, text: &str) -> bool {
+        let matched = self.starts_with(text);
+        if matched {
+            self.pos += text.chars().count();
+        }
+        matched
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), String> {
+        if !self.eat(text) {
+            return Err(format!("expected `{}`", text));
+        }
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(Piece::Char(c)) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn hole(&self, index: usize) -> Box<Expr> {
+        self.exprs[index].clone()
+    }
+
+    fn container(&self, expr: Box<Expr>) -> JSXExprContainer {
+        JSXExprContainer {
+            span: expr.span(),
+            expr: JSXExpr::Expr(expr),
+        }
+    }
+
+    fn parse_children(
+        &mut self,
+        closing: Option<&JSXElementName>,
+    ) -> Result<Vec<JSXElementChild>, String> {
+        let mut children = vec![];
+        let mut text = String::new();
+        let flush = |text: &mut String, children: &mut Vec<JSXElementChild>| {
+            if !text.is_empty() {
+                children.push(JSXElementChild::JSXText(JSXText {
+                    span: DUMMY_SP,
+                    value: text.as_str().into(),
+                    raw: text.as_str().into(),
+                }));
+                text.clear();
+            }
+        };
+
+        loop {
+            match self.peek() {
+                None => {
+                    if closing.is_some() {
+                        return Err("unclosed element".to_owned());
+                    }
+                    break;
+                }
+                Some(Piece::Hole(index)) => {
+                    flush(&mut text, &mut children);
+                    children.push(JSXElementChild::JSXExprContainer(
+                        self.container(self.hole(index)),
+                    ));
+                    self.pos += 1;
+                }
+                Some(Piece::Char('<')) if self.starts_with("<!--") => {
+                    self.pos += 4;
+                    while self.peek().is_some() && !self.eat("-->") {
+                        self.pos += 1;
+                    }
+                }
+                Some(Piece::Char('<')) if self.starts_with("</") => {
+                    flush(&mut text, &mut children);
+                    self.pos += 2;
+                    if !self.eat("/>") {
+                        let name = self.parse_name()?;
+                        if let Some(closing) = closing {
+                            if !same_name(&name, closing) {
+                                return Err("mismatched closing tag".to_owned());
+                            }
+                        }
+                        self.skip_whitespace();
+                        self.expect(">")?;
+                    }
+                    if closing.is_none() {
+                        return Err("unexpected closing tag".to_owned());
+                    }
+                    break;
+                }
+                Some(Piece::Char('<')) => {
+                    flush(&mut text, &mut children);
+                    children.push(JSXElementChild::JSXElement(Box::new(self.parse_element()?)));
+                }
+                Some(Piece::Char(c)) => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        flush(&mut text, &mut children);
+        Ok(children)
+    }
+
+    fn parse_element(&mut self) -> Result<JSXElement, String> {
+        self.expect("<")?;
+        let name = self.parse_name()?;
+        let mut attrs = vec![];
+        let self_closing = loop {
+            self.skip_whitespace();
+            if self.eat("/>") {
+                break true;
+            }
+            if self.eat(">") {
+                break false;
+            }
+            attrs.push(self.parse_attribute()?);
+        };
+        let is_void =
+            matches!(&name, JSXElementName::Ident(id) if VOID_ELEMENTS.contains(&&*id.sym));
+        let children = if self_closing || is_void {
+            vec![]
+        } else {
+            self.parse_children(Some(&name))?
+        };
+        let has_closing = !self_closing && !is_void;
+        Ok(JSXElement {
+            span: self.span,
+            opening: JSXOpeningElement {
+                name: name.clone(),
+                span: self.span,
+                attrs,
+                self_closing: !has_closing,
+                type_args: None,
+            },
+            children,
+            closing: has_closing.then_some(JSXClosingElement {
+                span: self.span,
+                name,
+            }),
+        })
+    }
+
+    /// Parses a tag name, either written out or passed as a `${Component}`
+    /// hole.
+    fn parse_name(&mut self) -> Result<JSXElementName, String> {
+        if let Some(Piece::Hole(index)) = self.peek() {
+            self.pos += 1;
+            return expr_to_name(&self.hole(index));
+        }
+        let name = self.parse_word();
+        if name.is_empty() {
+            return Err("expected a tag name".to_owned());
+        }
+        let mut parts = name.split('.');
+        let mut object = JSXObject::Ident(quote_ident!(parts.next().unwrap()));
+        let mut rest = parts.peekable();
+        if rest.peek().is_none() {
+            let JSXObject::Ident(id) = object else {
+                unreachable!()
+            };
+            return Ok(JSXElementName::Ident(id));
+        }
+        let mut prop = quote_ident!(rest.next().unwrap());
+        for part in rest {
+            object = JSXObject::JSXMemberExpr(Box::new(JSXMemberExpr { obj: object, prop }));
+            prop = quote_ident!(part);
+        }
+        Ok(JSXElementName::JSXMemberExpr(JSXMemberExpr {
+            obj: object,
+            prop,
+        }))
+    }
+
+    fn parse_word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(Piece::Char(c)) = self.peek() {
+            if c.is_whitespace() || matches!(c, '>' | '/' | '=' | '<' | '"' | '\'') {
+                break;
+            }
+            word.push(c);
+            self.pos += 1;
+        }
+        word
+    }
+
+    fn parse_attribute(&mut self) -> Result<JSXAttrOrSpread, String> {
+        if self.eat("...") {
+            let Some(Piece::Hole(index)) = self.peek() else {
+                return Err("expected `${}` after `...`".to_owned());
+            };
+            self.pos += 1;
+            return Ok(JSXAttrOrSpread::SpreadElement(SpreadElement {
+                dot3_token: DUMMY_SP,
+                expr: self.hole(index),
+            }));
+        }
+
+        let word = self.parse_word();
+        if word.is_empty() {
+            return Err("expected an attribute name".to_owned());
+        }
+        let name = match word.split_once(':') {
+            Some((ns, name)) => JSXAttrName::JSXNamespacedName(JSXNamespacedName {
+                ns: quote_ident!(ns),
+                name: quote_ident!(name),
+            }),
+            None => JSXAttrName::Ident(quote_ident!(word)),
+        };
+
+        self.skip_whitespace();
+        let value = if self.eat("=") {
+            self.skip_whitespace();
+            Some(self.parse_attribute_value())
+        } else {
+            None
+        };
+        Ok(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span: DUMMY_SP,
+            name,
+            value,
+        }))
+    }
+
+    /// Parses `${expr}`, `"text"` or a quoted mix of text and holes, which
+    /// becomes a template literal.
+    fn parse_attribute_value(&mut self) -> JSXAttrValue {
+        let quote = match self.peek() {
+            Some(Piece::Hole(index)) => {
+                self.pos += 1;
+                return JSXAttrValue::JSXExprContainer(self.container(self.hole(index)));
+            }
+            Some(Piece::Char(c @ ('"' | '\''))) => {
+                self.pos += 1;
+                Some(c)
+            }
+            _ => None,
+        };
+
+        let mut quasis = vec![String::new()];
+        let mut exprs = vec![];
+        loop {
+            match self.peek() {
+                None => break,
+                Some(Piece::Char(c)) if Some(c) == quote => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Piece::Char(c)) if quote.is_none() && (c.is_whitespace() || c == '>') => {
+                    break;
+                }
+                Some(Piece::Char('/')) if quote.is_none() && self.starts_with("/>") => break,
+                Some(Piece::Char(c)) => {
+                    quasis.last_mut().unwrap().push(c);
+                    self.pos += 1;
+                }
+                Some(Piece::Hole(index)) => {
+                    exprs.push(self.hole(index));
+                    quasis.push(String::new());
+                    self.pos += 1;
+                }
+            }
+        }
+
+        match (exprs.len(), quasis.as_slice()) {
+            (0, [text]) => JSXAttrValue::Lit(Lit::Str(text.as_str().into())),
+            (1, [before, after]) if before.is_empty() && after.is_empty() => {
+                JSXAttrValue::JSXExprContainer(self.container(exprs.remove(0)))
+            }
+            _ => {
+                let count = quasis.len();
+                let tpl = Tpl {
+                    span: DUMMY_SP,
+                    exprs,
+                    quasis: quasis
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, text)| TplElement {
+                            span: DUMMY_SP,
+                            tail: index + 1 == count,
+                            cooked: Some(text.as_str().into()),
+                            raw: text.replace('\\', "\\\\").replace('`', "\\`").into(),
+                        })
+                        .collect(),
+                };
+                JSXAttrValue::JSXExprContainer(self.container(Box::new(Expr::Tpl(tpl))))
+            }
+        }
+    }
+}
+
+const COMPONENT_NAME_ERROR: &str = "components must be identifiers or member expressions";
+
+fn expr_to_name(expr: &Expr) -> Result<JSXElementName, String> {
+    fn to_object(expr: &Expr) -> Result<JSXObject, String> {
+        match expr {
+            Expr::Ident(id) => Ok(JSXObject::Ident(id.clone())),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => Ok(JSXObject::JSXMemberExpr(Box::new(JSXMemberExpr {
+                obj: to_object(obj)?,
+                prop: prop.clone(),
+            }))),
+            _ => Err(COMPONENT_NAME_ERROR.to_owned()),
+        }
+    }
+    match expr {
+        Expr::Ident(id) => Ok(JSXElementName::Ident(id.clone())),
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => Ok(JSXElementName::JSXMemberExpr(JSXMemberExpr {
+            obj: to_object(obj)?,
+            prop: prop.clone(),
+        })),
+        _ => Err(COMPONENT_NAME_ERROR.to_owned()),
+    }
+}
+
+fn same_name(a: &JSXElementName, b: &JSXElementName) -> bool {
+    fn object_eq(a: &JSXObject, b: &JSXObject) -> bool {
+        match (a, b) {
+            (JSXObject::Ident(a), JSXObject::Ident(b)) => a.sym == b.sym,
+            (JSXObject::JSXMemberExpr(a), JSXObject::JSXMemberExpr(b)) => {
+                a.prop.sym == b.prop.sym && object_eq(&a.obj, &b.obj)
+            }
+            _ => false,
+        }
+    }
+    match (a, b) {
+        (JSXElementName::Ident(a), JSXElementName::Ident(b)) => a.sym == b.sym,
+        (JSXElementName::JSXMemberExpr(a), JSXElementName::JSXMemberExpr(b)) => {
+            a.prop.sym == b.prop.sym && object_eq(&a.obj, &b.obj)
+        }
+        _ => false,
+    }
+}
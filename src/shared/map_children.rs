@@ -0,0 +1,206 @@
+use crate::{shared::transform::ConstInit, TransformVisitor};
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+/// Turns the references to a `.map` callback's index parameter into calls,
+/// as `<For>` passes the index as an accessor.
+struct IndexAccessor {
+    index: Id,
+}
+
+impl VisitMut for IndexAccessor {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Ident(id) if id.to_id() == self.index => {
+                *expr = Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: Callee::Expr(Box::new(Expr::Ident(id.clone()))),
+                    args: vec![],
+                    type_args: None,
+                });
+            }
+            _ => expr.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        match prop {
+            Prop::Shorthand(id) if id.to_id() == self.index => {
+                *prop = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(id.clone()),
+                    value: Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: Callee::Expr(Box::new(Expr::Ident(id.clone()))),
+                        args: vec![],
+                        type_args: None,
+                    })),
+                });
+            }
+            _ => prop.visit_mut_children_with(self),
+        }
+    }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(ParenExpr { expr, .. }) => unwrap_paren(expr),
+        _ => expr,
+    }
+}
+
+fn is_jsx(expr: &Expr) -> bool {
+    matches!(
+        unwrap_paren(expr),
+        Expr::JSXElement(_) | Expr::JSXFragment(_)
+    )
+}
+
+/// Whether `callback` can be handed to `<For>`: it returns JSX and takes
+/// at most the item and its index.
+fn is_row_callback(callback: &Expr) -> bool {
+    match unwrap_paren(callback) {
+        Expr::Arrow(ArrowExpr { params, body, .. }) if params.len() <= 2 => {
+            params.iter().skip(1).all(Pat::is_ident)
+                && match &**body {
+                    BlockStmtOrExpr::Expr(expr) => is_jsx(expr),
+                    BlockStmtOrExpr::BlockStmt(block) => returns_jsx(block),
+                }
+        }
+        Expr::Fn(FnExpr { function, .. }) if function.params.len() <= 2 => {
+            function
+                .params
+                .iter()
+                .skip(1)
+                .all(|param| param.pat.is_ident())
+                && function.body.as_ref().is_some_and(returns_jsx)
+        }
+        _ => false,
+    }
+}
+
+/// Makes `callback` read its index parameter, if any, through the accessor
+/// `<For>` passes in its place.
+fn call_index(callback: &mut Expr) {
+    let index = match callback {
+        Expr::Paren(ParenExpr { expr, .. }) => return call_index(expr),
+        Expr::Arrow(ArrowExpr { params, .. }) => params.get(1),
+        Expr::Fn(FnExpr { function, .. }) => function.params.get(1).map(|param| &param.pat),
+        _ => None,
+    };
+    if let Some(Pat::Ident(index)) = index {
+        let mut visitor = IndexAccessor {
+            index: index.to_id(),
+        };
+        match callback {
+            Expr::Arrow(arrow) => arrow.body.visit_mut_with(&mut visitor),
+            Expr::Fn(FnExpr { function, .. }) => function.body.visit_mut_with(&mut visitor),
+            _ => {}
+        }
+    }
+}
+
+fn returns_jsx(block: &BlockStmt) -> bool {
+    matches!(block.stmts.last(), Some(Stmt::Return(ReturnStmt { arg: Some(arg), .. })) if is_jsx(arg))
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Whether `.map` on `expr` is known not to be `Array.prototype.map`,
+    /// like on a literal, an object or a constant holding one.
+    fn is_non_array(&self, expr: &Expr) -> bool {
+        match unwrap_paren(expr) {
+            Expr::Lit(_)
+            | Expr::Tpl(_)
+            | Expr::Object(_)
+            | Expr::Fn(_)
+            | Expr::Arrow(_)
+            | Expr::Class(_) => true,
+            Expr::Ident(id) => match self.binding_collector.const_var_bindings.get(&id.to_id()) {
+                Some(ConstInit::Foldable(init)) => {
+                    !matches!(init, Expr::Ident(_)) && self.is_non_array(init)
+                }
+                Some(init) => matches!(init, ConstInit::Function | ConstInit::Object),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Rewrites `{list.map(item => <li />)}` children to
+    /// `<For each={list}>{item => <li />}</For>`, so rows are keyed and kept
+    /// across updates instead of being recreated.
+    pub fn map_children_to_for(&mut self, children: &[JSXElementChild]) -> Vec<JSXElementChild> {
+        children
+            .iter()
+            .map(|child| {
+                let JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    span,
+                }) = child
+                else {
+                    return child.clone();
+                };
+                let Expr::Call(CallExpr {
+                    callee: Callee::Expr(callee),
+                    args,
+                    ..
+                }) = unwrap_paren(expr)
+                else {
+                    return child.clone();
+                };
+                let Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(method),
+                    ..
+                }) = &**callee
+                else {
+                    return child.clone();
+                };
+                if &method.sym != "map"
+                    || args.len() != 1
+                    || args[0].spread.is_some()
+                    || !is_row_callback(&args[0].expr)
+                    || self.is_non_array(obj)
+                {
+                    return child.clone();
+                }
+                let mut callback = args[0].expr.clone();
+                call_index(&mut callback);
+
+                let name = JSXElementName::Ident(self.register_import_method("For"));
+                JSXElementChild::JSXElement(Box::new(JSXElement {
+                    span: *span,
+                    opening: JSXOpeningElement {
+                        name: name.clone(),
+                        span: DUMMY_SP,
+                        attrs: vec![JSXAttrOrSpread::JSXAttr(JSXAttr {
+                            span: DUMMY_SP,
+                            name: JSXAttrName::Ident(Ident::new("each".into(), DUMMY_SP)),
+                            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                span: DUMMY_SP,
+                                expr: JSXExpr::Expr(obj.clone()),
+                            })),
+                        })],
+                        self_closing: false,
+                        type_args: None,
+                    },
+                    children: vec![JSXElementChild::JSXExprContainer(JSXExprContainer {
+                        span: DUMMY_SP,
+                        expr: JSXExpr::Expr(callback),
+                    })],
+                    closing: Some(JSXClosingElement {
+                        span: DUMMY_SP,
+                        name,
+                    }),
+                }))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,518 @@
+use super::utils::{core_module, emit_warning, is_component};
+use crate::TransformVisitor;
+use std::collections::HashMap;
+use swc_atoms::JsWord;
+use swc_core::{
+    common::{comments::Comments, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{private_ident, quote_ident},
+        visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+    },
+};
+
+/// Finds assignments to any of `bindings`, which can't become prop accesses.
+struct ReassignFinder<'a> {
+    bindings: &'a HashMap<Id, JsWord>,
+    found: bool,
+}
+
+impl Visit for ReassignFinder<'_> {
+    fn visit_binding_ident(&mut self, n: &BindingIdent) {
+        if self.bindings.contains_key(&n.id.to_id()) {
+            self.found = true;
+        }
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        let target = match &n.left {
+            PatOrExpr::Expr(expr) => expr.as_ident(),
+            PatOrExpr::Pat(pat) => match &**pat {
+                Pat::Expr(expr) => expr.as_ident(),
+                _ => None,
+            },
+        };
+        if target.is_some_and(|id| self.bindings.contains_key(&id.to_id())) {
+            self.found = true;
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr) {
+        if let Expr::Ident(id) = &*n.arg {
+            if self.bindings.contains_key(&id.to_id()) {
+                self.found = true;
+            }
+        }
+        n.visit_children_with(self);
+    }
+}
+
+/// Finds JSX tags naming bindings whose key isn't an identifier, since
+/// `<props["my-tag"]>` can't be written in JSX.
+struct TagFinder<'a> {
+    bindings: &'a HashMap<Id, JsWord>,
+    found: bool,
+}
+
+impl TagFinder<'_> {
+    fn check(&mut self, id: &Ident) {
+        if let Some(key) = self.bindings.get(&id.to_id()) {
+            self.found |= Ident::verify_symbol(key).is_err();
+        }
+    }
+}
+
+impl Visit for TagFinder<'_> {
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName) {
+        match n {
+            JSXElementName::Ident(id) if is_component(&id.sym) => self.check(id),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_object(&mut self, n: &JSXObject) {
+        match n {
+            JSXObject::Ident(id) => self.check(id),
+            _ => n.visit_children_with(self),
+        }
+    }
+}
+
+/// Replaces reads of destructured bindings with reads from `props`.
+struct PropsReplacer<'a> {
+    bindings: &'a HashMap<Id, JsWord>,
+    props: &'a Ident,
+}
+
+impl PropsReplacer<'_> {
+    fn access(&self, key: &JsWord) -> Expr {
+        Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(Expr::Ident(self.props.clone())),
+            prop: if Ident::verify_symbol(key).is_ok() {
+                MemberProp::Ident(quote_ident!(key.clone()))
+            } else {
+                MemberProp::Computed(ComputedPropName {
+                    span: DUMMY_SP,
+                    expr: Box::new(Expr::Lit(Lit::Str(key.clone().into()))),
+                })
+            },
+        })
+    }
+
+    fn jsx_access(&self, id: &Ident) -> Option<JSXMemberExpr> {
+        let key = self.bindings.get(&id.to_id())?;
+        Some(JSXMemberExpr {
+            obj: JSXObject::Ident(self.props.clone()),
+            prop: quote_ident!(id.span, key.clone()),
+        })
+    }
+}
+
+impl VisitMut for PropsReplacer<'_> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Ident(id) = expr {
+            if let Some(key) = self.bindings.get(&id.to_id()) {
+                *expr = self.access(key);
+            }
+            return;
+        }
+        expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        if let Prop::Shorthand(id) = prop {
+            if let Some(key) = self.bindings.get(&id.to_id()) {
+                *prop = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(id.clone()),
+                    value: Box::new(self.access(key)),
+                });
+            }
+            return;
+        }
+        prop.visit_mut_children_with(self);
+    }
+
+    /// `<Tag>` becomes `<props.as>`. Lowercase names are intrinsic elements,
+    /// not references.
+    fn visit_mut_jsx_element_name(&mut self, name: &mut JSXElementName) {
+        match name {
+            JSXElementName::Ident(id) if is_component(&id.sym) => {
+                if let Some(access) = self.jsx_access(id) {
+                    *name = JSXElementName::JSXMemberExpr(access);
+                }
+            }
+            _ => name.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_jsx_object(&mut self, object: &mut JSXObject) {
+        match object {
+            JSXObject::Ident(id) => {
+                if let Some(access) = self.jsx_access(id) {
+                    *object = JSXObject::JSXMemberExpr(Box::new(access));
+                }
+            }
+            _ => object.visit_mut_children_with(self),
+        }
+    }
+}
+
+/// What a destructuring pattern in the props position takes apart.
+#[derive(Default)]
+struct DestructuredProps {
+    bindings: HashMap<Id, JsWord>,
+    keys: Vec<JsWord>,
+    defaults: Vec<(JsWord, Box<Expr>)>,
+    rest: Option<BindingIdent>,
+}
+
+fn prop_key(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(id) => Some(id.sym.clone()),
+        PropName::Str(str) => Some(str.value.clone()),
+        _ => None,
+    }
+}
+
+/// Returns `None` for patterns that have no equivalent in prop accesses,
+/// such as nested destructuring or computed keys.
+fn analyze_pattern(pat: &ObjectPat) -> Option<DestructuredProps> {
+    let mut props = DestructuredProps::default();
+    for prop in &pat.props {
+        match prop {
+            ObjectPatProp::Assign(AssignPatProp { key, value, .. }) => {
+                props.bindings.insert(key.to_id(), key.sym.clone());
+                props.keys.push(key.sym.clone());
+                if let Some(value) = value {
+                    props.defaults.push((key.sym.clone(), value.clone()));
+                }
+            }
+            ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                let key = prop_key(key)?;
+                match &**value {
+                    Pat::Ident(binding) => {
+                        props.bindings.insert(binding.to_id(), key.clone());
+                    }
+                    Pat::Assign(AssignPat { left, right, .. }) => {
+                        let Pat::Ident(binding) = &**left else {
+                            return None;
+                        };
+                        props.bindings.insert(binding.to_id(), key.clone());
+                        props.defaults.push((key.clone(), right.clone()));
+                    }
+                    _ => return None,
+                }
+                props.keys.push(key);
+            }
+            ObjectPatProp::Rest(RestPat { arg, .. }) => match &**arg {
+                Pat::Ident(binding) => props.rest = Some(binding.clone()),
+                _ => return None,
+            },
+        }
+    }
+    Some(props)
+}
+
+enum Body<'a> {
+    Function(&'a mut BlockStmt),
+    Arrow(&'a mut BlockStmtOrExpr),
+}
+
+impl Body<'_> {
+    fn has_jsx<C: Comments>(&self, transform: &TransformVisitor<C>) -> bool {
+        match self {
+            Body::Function(block) => transform.contains_jsx(&**block),
+            Body::Arrow(body) => transform.contains_jsx(&**body),
+        }
+    }
+
+    fn visit<V: Visit>(&self, visitor: &mut V) {
+        match self {
+            Body::Function(block) => block.visit_with(visitor),
+            Body::Arrow(body) => body.visit_with(visitor),
+        }
+    }
+
+    fn visit_mut<V: VisitMut>(&mut self, visitor: &mut V) {
+        match self {
+            Body::Function(block) => block.visit_mut_with(visitor),
+            Body::Arrow(body) => body.visit_mut_with(visitor),
+        }
+    }
+
+    fn prepend(&mut self, stmts: Vec<Stmt>) {
+        if stmts.is_empty() {
+            return;
+        }
+        let block = match self {
+            Body::Function(block) => block,
+            Body::Arrow(body) => {
+                if let BlockStmtOrExpr::Expr(expr) = &mut **body {
+                    let arg = std::mem::replace(
+                        expr,
+                        Box::new(Expr::Invalid(Invalid { span: DUMMY_SP })),
+                    );
+                    **body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts: vec![Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(arg),
+                        })],
+                    });
+                }
+                let BlockStmtOrExpr::BlockStmt(block) = &mut **body else {
+                    unreachable!()
+                };
+                block
+            }
+        };
+        block.stmts.splice(0..0, stmts);
+    }
+}
+
+struct PropsDestructuring<'a, C: Comments> {
+    transform: &'a TransformVisitor<C>,
+    imports: HashMap<String, Ident>,
+}
+
+impl<C: Comments> PropsDestructuring<'_, C> {
+    fn import(&mut self, name: &str) -> Ident {
+        self.imports
+            .entry(name.to_string())
+            .or_insert_with(|| private_ident!(format!("_${}", name)))
+            .clone()
+    }
+
+    /// Turns the destructured first parameter of component `name` back into
+    /// a `props` object whose fields are read where the bindings were used.
+    fn rewrite(&mut self, name: &str, param: &mut Pat, mut body: Body) {
+        let (pat, default) = match &*param {
+            Pat::Assign(AssignPat { left, right, .. }) => (&**left, Some(right.clone())),
+            pat => (pat, None),
+        };
+        let Pat::Object(pat) = pat else {
+            return;
+        };
+        if !body.has_jsx(self.transform) {
+            return;
+        }
+
+        let span = pat.span;
+        let Some(mut props) = analyze_pattern(pat).filter(|props| {
+            let mut tags = TagFinder {
+                bindings: &props.bindings,
+                found: false,
+            };
+            body.visit(&mut tags);
+            !tags.found
+        }) else {
+            emit_warning(
+                span,
+                &format!(
+                    "The props of `{}` are destructured in a way that can't be kept reactive",
+                    name
+                ),
+            );
+            return;
+        };
+        let mut reassign = ReassignFinder {
+            bindings: &props.bindings,
+            found: false,
+        };
+        body.visit(&mut reassign);
+        if reassign.found {
+            emit_warning(
+                span,
+                &format!(
+                    "Destructured props of `{}` are reassigned, so they can't be read from \
+                     `props` and aren't reactive",
+                    name
+                ),
+            );
+            return;
+        }
+
+        let props_id = private_ident!("props");
+        let mut replacer = PropsReplacer {
+            bindings: &props.bindings,
+            props: &props_id,
+        };
+        body.visit_mut(&mut replacer);
+        for (_, value) in props.defaults.iter_mut() {
+            value.visit_mut_with(&mut replacer);
+        }
+
+        let mut stmts = vec![];
+        if !props.defaults.is_empty() {
+            let defaults = ObjectLit {
+                span: DUMMY_SP,
+                props: props
+                    .defaults
+                    .into_iter()
+                    .map(|(key, value)| {
+                        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: if Ident::verify_symbol(&key).is_ok() {
+                                PropName::Ident(quote_ident!(key))
+                            } else {
+                                PropName::Str(key.into())
+                            },
+                            value,
+                        })))
+                    })
+                    .collect(),
+            };
+            stmts.push(Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: AssignOp::Assign,
+                    left: PatOrExpr::Pat(Box::new(Pat::Ident(props_id.clone().into()))),
+                    right: Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: Callee::Expr(Box::new(Expr::Ident(self.import("mergeProps")))),
+                        args: vec![
+                            ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Object(defaults)),
+                            },
+                            ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Ident(props_id.clone())),
+                            },
+                        ],
+                        type_args: None,
+                    })),
+                })),
+            }));
+        }
+        if let Some(rest) = props.rest {
+            stmts.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Array(ArrayPat {
+                        span: DUMMY_SP,
+                        elems: vec![None, Some(Pat::Ident(rest))],
+                        optional: false,
+                        type_ann: None,
+                    }),
+                    init: Some(Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: Callee::Expr(Box::new(Expr::Ident(self.import("splitProps")))),
+                        args: vec![
+                            ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Ident(props_id.clone())),
+                            },
+                            ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Array(ArrayLit {
+                                    span: DUMMY_SP,
+                                    elems: props
+                                        .keys
+                                        .into_iter()
+                                        .map(|key| {
+                                            Some(ExprOrSpread {
+                                                spread: None,
+                                                expr: Box::new(Expr::Lit(Lit::Str(key.into()))),
+                                            })
+                                        })
+                                        .collect(),
+                                })),
+                            },
+                        ],
+                        type_args: None,
+                    }))),
+                    definite: false,
+                }],
+            }))));
+        }
+        // `({ a } = {})` keeps its default as `props = {}`
+        *param = match default {
+            Some(right) => Pat::Assign(AssignPat {
+                span: DUMMY_SP,
+                left: Box::new(Pat::Ident(props_id.into())),
+                right,
+            }),
+            None => Pat::Ident(props_id.into()),
+        };
+        body.prepend(stmts);
+    }
+
+    fn rewrite_function(&mut self, name: &str, function: &mut Function) {
+        if let (Some(param), Some(body)) = (function.params.first_mut(), &mut function.body) {
+            self.rewrite(name, &mut param.pat, Body::Function(body));
+        }
+    }
+
+    fn rewrite_expr(&mut self, name: &str, expr: &mut Expr) {
+        match expr {
+            Expr::Arrow(ArrowExpr { params, body, .. }) => {
+                if let Some(param) = params.first_mut() {
+                    self.rewrite(name, param, Body::Arrow(body));
+                }
+            }
+            Expr::Fn(FnExpr { function, .. }) => self.rewrite_function(name, function),
+            Expr::Paren(ParenExpr { expr, .. }) => self.rewrite_expr(name, expr),
+            _ => {}
+        }
+    }
+}
+
+impl<C: Comments> VisitMut for PropsDestructuring<'_, C> {
+    fn visit_mut_fn_decl(&mut self, n: &mut FnDecl) {
+        n.visit_mut_children_with(self);
+        if is_component(&n.ident.sym) {
+            self.rewrite_function(&n.ident.sym, &mut n.function);
+        }
+    }
+
+    fn visit_mut_var_declarator(&mut self, n: &mut VarDeclarator) {
+        n.visit_mut_children_with(self);
+        if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) = (&n.name, &mut n.init) {
+            if is_component(&id.sym) {
+                self.rewrite_expr(&id.sym, init);
+            }
+        }
+    }
+
+    fn visit_mut_fn_expr(&mut self, n: &mut FnExpr) {
+        n.visit_mut_children_with(self);
+        if let Some(ident) = &n.ident {
+            if is_component(&ident.sym) {
+                self.rewrite_function(&ident.sym, &mut n.function);
+            }
+        }
+    }
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Keeps destructured props of components reactive by reading them from
+    /// the props object where they're used, with defaults applied through
+    /// `mergeProps` and rest props split off with `splitProps`. Both helpers
+    /// are imported from the core package of the runtime module.
+    pub fn transform_destructured_props(&mut self, module: &mut Module) {
+        let mut visitor = PropsDestructuring {
+            transform: self,
+            imports: HashMap::new(),
+        };
+        module.visit_mut_with(&mut visitor);
+        let helpers = visitor.imports;
+        // without a separate core package, the runtime module exports them
+        let imports = if core_module(&self.config.module_name) == self.config.module_name {
+            &mut self.imports
+        } else {
+            &mut self.core_imports
+        };
+        for (name, id) in helpers {
+            imports.entry(name).or_insert(id);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use super::utils::{convert_jsx_identifier, emit_warning, is_component};
+use crate::TransformVisitor;
+use swc_core::{common::comments::Comments, ecma::ast::*};
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(ParenExpr { expr, .. }) => unwrap_paren(expr),
+        _ => expr,
+    }
+}
+
+/// Positions where handing over the function itself is intended.
+fn expects_function(key: &str) -> bool {
+    key.starts_with("on") || key == "ref" || key.starts_with("use:")
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Warns about accessors such as `count` in `{count}` that are used where
+    /// their value, `count()`, was most likely meant.
+    fn warn_uncalled_function(&self, expr: &Expr) {
+        let Expr::Ident(id) = unwrap_paren(expr) else {
+            return;
+        };
+        // components are passed around as values on purpose
+        if is_component(&id.sym) || !self.binding_collector.is_function(&id.to_id()) {
+            return;
+        }
+        emit_warning(
+            id.span,
+            &format!(
+                "`{0}` is a function and is used without being called, did you mean `{0}()`?",
+                id.sym
+            ),
+        );
+    }
+
+    /// Checks the attributes of a native element, which take values.
+    pub fn warn_uncalled_functions_in_attributes(&self, attributes: &[JSXAttrOrSpread]) {
+        for attribute in attributes {
+            let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name,
+                value:
+                    Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        expr: JSXExpr::Expr(expr),
+                        ..
+                    })),
+                ..
+            }) = attribute
+            else {
+                continue;
+            };
+            if !expects_function(&convert_jsx_identifier(name).1) {
+                self.warn_uncalled_function(expr);
+            }
+        }
+    }
+
+    /// Component children may be render functions, so only signal getters
+    /// are reported there.
+    pub fn warn_uncalled_signals_in_component_children(&self, children: &[JSXElementChild]) {
+        for child in children {
+            if let JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) = child
+            {
+                if matches!(unwrap_paren(expr), Expr::Ident(id) if self.binding_collector.signal_getters.contains(&id.to_id()))
+                {
+                    self.warn_uncalled_function(expr);
+                }
+            }
+        }
+    }
+
+    pub fn warn_uncalled_functions_in_children(&self, children: &[JSXElementChild]) {
+        for child in children {
+            if let JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) = child
+            {
+                self.warn_uncalled_function(expr);
+            }
+        }
+    }
+}
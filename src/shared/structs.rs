@@ -5,10 +5,17 @@ use std::{
     fmt::Debug,
 };
 use swc_core::{
-    common::comments::Comments,
-    ecma::{ast::*, minifier::eval::Evaluator, utils::private_ident},
+    common::{comments::Comments, errors::SourceMapper, sync::Lrc, Mark},
+    ecma::{ast::*, utils::private_ident},
 };
 
+/// CSS of the file's `<style scoped>` elements, already rewritten to only
+/// match elements carrying `attribute`.
+pub struct ScopedStyle {
+    pub attribute: String,
+    pub css: String,
+}
+
 pub struct TemplateConstruction {
     pub template: String,
     pub id: Ident,
@@ -53,10 +60,22 @@ where
     pub template: Option<TemplateInstantiation>,
     pub templates: Vec<TemplateConstruction>,
     pub imports: HashMap<String, Ident>,
+    /// Helpers imported from the core package of the runtime module, like
+    /// `mergeProps` from `solid-js`.
+    pub core_imports: HashMap<String, Ident>,
     pub events: HashSet<String>,
     pub comments: C,
-    pub evaluator: Option<Evaluator>,
     pub binding_collector: VarBindingCollector,
+    /// Mark the resolver applied to identifiers with no binding in scope; used
+    /// to tell runtime built-ins apart from local or imported components.
+    pub unresolved_mark: Mark,
+    pub scoped_style: Option<ScopedStyle>,
+    /// Asset URLs imported by the file, with the binding of each import.
+    pub asset_imports: Vec<(String, Ident)>,
+    /// Event handlers moved to module-level constants.
+    pub hoisted_handlers: Vec<VarDeclarator>,
+    /// Source of the file, to read JSX text exactly as written.
+    pub source_map: Option<Lrc<dyn SourceMapper>>,
     uid_identifier_map: HashMap<String, usize>,
 }
 
@@ -64,20 +83,30 @@ impl<C> TransformVisitor<C>
 where
     C: Comments,
 {
-    pub fn new(config: Config, comments: C) -> Self {
+    pub fn new(config: Config, comments: C, unresolved_mark: Mark) -> Self {
         Self {
             config,
             templates: vec![],
             template: None,
             imports: Default::default(),
+            core_imports: Default::default(),
             events: Default::default(),
             comments,
-            evaluator: Default::default(),
             binding_collector: VarBindingCollector::new(),
+            unresolved_mark,
+            scoped_style: None,
+            asset_imports: vec![],
+            hoisted_handlers: vec![],
+            source_map: None,
             uid_identifier_map: HashMap::new(),
         }
     }
 
+    pub fn with_source_map(mut self, source_map: Lrc<dyn SourceMapper>) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
     pub fn generate_uid_identifier(&mut self, name: &str) -> Ident {
         let name = if name.starts_with('_') {
             name.to_string()
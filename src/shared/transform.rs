@@ -1,25 +1,68 @@
 use super::structs::TemplateInstantiation;
-use crate::shared::utils::{escape_backticks, escape_html, trim_whitespace};
 pub use crate::shared::{
     structs::TransformVisitor,
     utils::{get_tag_name, is_component},
 };
+use crate::{
+    config::{Generate, Whitespace},
+    shared::evaluate::is_foldable,
+    shared::utils::{core_module, escape_backticks, escape_html, normalize_whitespace},
+};
 use std::collections::HashSet;
+use swc_atoms::JsWord;
 use swc_core::{
     common::{
         collections::{AHashMap, AHashSet},
         comments::Comments,
+        util::take::Take,
         DUMMY_SP,
     },
     ecma::{
         ast::*,
-        utils::private_ident,
+        utils::{find_pat_ids, prepend_stmt, private_ident, quote_ident},
         visit::{Visit, VisitMut, VisitMutWith, VisitWith},
     },
 };
+/// What is known of the value of a `const` binding. Only initializers the
+/// constant folder can use are kept, so large ones such as components aren't
+/// copied.
+pub enum ConstInit {
+    /// An initializer made of literals, operators and other bindings.
+    Foldable(Expr),
+    /// A function or arrow function.
+    Function,
+    /// An object literal or a class, which are never arrays.
+    Object,
+    /// An import, or a value nothing is known about.
+    Unknown,
+}
+
+impl ConstInit {
+    fn new(init: Option<&Expr>) -> Self {
+        match init {
+            Some(Expr::Fn(_) | Expr::Arrow(_)) => ConstInit::Function,
+            Some(Expr::Object(_) | Expr::Class(_)) => ConstInit::Object,
+            Some(init) if is_foldable(init) => ConstInit::Foldable(init.clone()),
+            _ => ConstInit::Unknown,
+        }
+    }
+}
+
+#[derive(Default)]
 pub struct VarBindingCollector {
-    pub const_var_bindings: AHashMap<Id, Option<Expr>>,
+    pub const_var_bindings: AHashMap<Id, ConstInit>,
     pub function_bindings: AHashSet<Id>,
+    /// Accessors returned by `createSignal` and `createMemo`.
+    pub signal_getters: AHashSet<Id>,
+    /// Bindings declared at the top level of the module, imports included.
+    pub module_bindings: AHashSet<Id>,
+    /// Bindings imported by the module.
+    pub import_bindings: AHashSet<Id>,
+    /// The runtime module, whose package `createSignal` and `createMemo`
+    /// are recognized from.
+    pub module_name: String,
+    /// Local names of `createSignal` and `createMemo`, by imported name.
+    signal_factories: AHashMap<Id, JsWord>,
 }
 
 impl VarBindingCollector {
@@ -27,45 +70,157 @@ impl VarBindingCollector {
         Self {
             const_var_bindings: Default::default(),
             function_bindings: Default::default(),
+            signal_getters: Default::default(),
+            module_bindings: Default::default(),
+            import_bindings: Default::default(),
+            module_name: Default::default(),
+            signal_factories: Default::default(),
+        }
+    }
+
+    /// Returns the initializer of a `const` binding. `let` and `var` bindings
+    /// are never folded, since they may be reassigned anywhere in their scope.
+    pub fn constant_init(&self, id: &Id) -> Option<&Expr> {
+        match self.const_var_bindings.get(id)? {
+            ConstInit::Foldable(init) => Some(init),
+            _ => None,
         }
     }
 
-    fn collect_pat(&mut self, pat: &Pat, init: Option<Expr>) {
+    /// Whether `id` is known to hold a function: a function declaration, a
+    /// `const` initialized with a function, or a signal getter.
+    pub fn is_function(&self, id: &Id) -> bool {
+        self.function_bindings.contains(id)
+            || self.signal_getters.contains(id)
+            || matches!(self.const_var_bindings.get(id), Some(ConstInit::Function))
+    }
+
+    /// Whether `source` is the runtime module or the package it belongs to,
+    /// e.g. `solid-js` for `solid-js/web`.
+    fn is_runtime_source(&self, source: &str) -> bool {
+        source == self.module_name || source == core_module(&self.module_name)
+    }
+
+    /// Which of `createSignal` and `createMemo` `init` calls, if any.
+    fn signal_factory(&self, init: Option<&Expr>) -> Option<&str> {
+        let Some(Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        })) = init
+        else {
+            return None;
+        };
+        let Expr::Ident(callee) = &**callee else {
+            return None;
+        };
+        self.signal_factories
+            .get(&callee.to_id())
+            .map(|name| &**name)
+    }
+
+    fn collect_pat(&mut self, pat: &Pat, init: Option<&Expr>, kind: VarDeclKind) {
         match pat {
             Pat::Ident(id) => {
-                self.const_var_bindings.insert(id.to_id(), init);
+                if kind == VarDeclKind::Const {
+                    // `const double = createMemo(() => count() * 2)`
+                    if self.signal_factory(init) == Some("createMemo") {
+                        self.signal_getters.insert(id.to_id());
+                    }
+                    self.const_var_bindings
+                        .insert(id.to_id(), ConstInit::new(init));
+                }
             }
             Pat::Array(a) => {
+                // `const [count, setCount] = createSignal(0)` yields an accessor
+                if let Some(Some(Pat::Ident(getter))) = a.elems.first() {
+                    if kind == VarDeclKind::Const
+                        && self.signal_factory(init) == Some("createSignal")
+                    {
+                        self.signal_getters.insert(getter.to_id());
+                    }
+                }
                 for p in a.elems.iter().flatten() {
-                    self.collect_pat(p, None);
+                    self.collect_pat(p, None, kind);
                 }
             }
-            Pat::Rest(rest) => self.collect_pat(&rest.arg, None),
+            Pat::Rest(rest) => self.collect_pat(&rest.arg, None, kind),
             _ => {}
         };
     }
 }
 
 impl Visit for VarBindingCollector {
+    fn visit_module(&mut self, module: &Module) {
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    if self.is_runtime_source(&import.src.value) {
+                        for spec in &import.specifiers {
+                            let ImportSpecifier::Named(spec) = spec else {
+                                continue;
+                            };
+                            let name = match &spec.imported {
+                                Some(ModuleExportName::Ident(id)) => id.sym.clone(),
+                                Some(ModuleExportName::Str(str)) => str.value.clone(),
+                                None => spec.local.sym.clone(),
+                            };
+                            if &name == "createSignal" || &name == "createMemo" {
+                                self.signal_factories.insert(spec.local.to_id(), name);
+                            }
+                        }
+                    }
+                    self.import_bindings
+                        .extend(import.specifiers.iter().map(|spec| match spec {
+                            ImportSpecifier::Named(s) => s.local.to_id(),
+                            ImportSpecifier::Default(s) => s.local.to_id(),
+                            ImportSpecifier::Namespace(s) => s.local.to_id(),
+                        }));
+                    self.module_bindings
+                        .extend(self.import_bindings.iter().cloned());
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => decl,
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                _ => continue,
+            };
+            match decl {
+                Decl::Var(var) => {
+                    for declarator in &var.decls {
+                        self.module_bindings
+                            .extend(find_pat_ids::<_, Id>(&declarator.name));
+                    }
+                }
+                Decl::Fn(f) => {
+                    self.module_bindings.insert(f.ident.to_id());
+                }
+                Decl::Class(c) => {
+                    self.module_bindings.insert(c.ident.to_id());
+                }
+                _ => {}
+            }
+        }
+        module.visit_children_with(self);
+    }
+
     fn visit_import_decl(&mut self, import_dect: &ImportDecl) {
         for spec in &import_dect.specifiers {
             match spec {
-                ImportSpecifier::Named(s) => self.const_var_bindings.insert(s.local.to_id(), None),
-                ImportSpecifier::Default(s) => {
-                    self.const_var_bindings.insert(s.local.to_id(), None)
-                }
-                ImportSpecifier::Namespace(s) => {
-                    self.const_var_bindings.insert(s.local.to_id(), None)
-                }
+                ImportSpecifier::Named(s) => self
+                    .const_var_bindings
+                    .insert(s.local.to_id(), ConstInit::Unknown),
+                ImportSpecifier::Default(s) => self
+                    .const_var_bindings
+                    .insert(s.local.to_id(), ConstInit::Unknown),
+                ImportSpecifier::Namespace(s) => self
+                    .const_var_bindings
+                    .insert(s.local.to_id(), ConstInit::Unknown),
             };
         }
     }
 
     fn visit_var_decl(&mut self, n: &VarDecl) {
-        if n.kind == VarDeclKind::Const {
-            for decl in &n.decls {
-                self.collect_pat(&decl.name, decl.init.clone().map(|v| *v));
-            }
+        for decl in &n.decls {
+            self.collect_pat(&decl.name, decl.init.as_deref(), n.kind);
         }
         n.visit_children_with(self);
     }
@@ -75,62 +230,252 @@ impl Visit for VarBindingCollector {
     }
 }
 
-pub struct ThisBlockVisitor {
-    this_id: Option<Ident>,
-    has_jsx: bool,
+/// The bindings a function-like scope has to capture so expressions moved
+/// into generated getters and thunks keep referring to it.
+#[derive(Default)]
+struct Captures {
+    this: Option<Ident>,
+    arguments: Option<Ident>,
+    new_target: Option<Ident>,
 }
 
-impl ThisBlockVisitor {
-    pub fn new() -> Self {
+impl Captures {
+    fn declaration(self) -> Option<Stmt> {
+        let decls: Vec<_> = [
+            (self.this, Expr::This(ThisExpr { span: DUMMY_SP })),
+            (self.arguments, Expr::Ident(quote_ident!("arguments"))),
+            (
+                self.new_target,
+                Expr::MetaProp(MetaPropExpr {
+                    span: DUMMY_SP,
+                    kind: MetaPropKind::NewTarget,
+                }),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(id, init)| {
+            Some(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(id?.into()),
+                init: Some(Box::new(init)),
+                definite: false,
+            })
+        })
+        .collect();
+        if decls.is_empty() {
+            return None;
+        }
+        Some(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls,
+        }))))
+    }
+}
+
+/// Replaces `this`, `arguments` and `new.target` inside JSX with constants
+/// declared at the top of the enclosing function-like scope, as the
+/// expressions may end up in getters that bind their own.
+///
+/// Arrow functions inside JSX are transparent, while other functions start a
+/// scope of their own. Scopes without a body to declare the constants in,
+/// such as arrow expression bodies and class property initializers, are
+/// turned into blocks or wrapped in an arrow IIFE. The holes of `html`
+/// templates count as JSX, since they are compiled the same way.
+pub struct LexicalCaptureVisitor<'a, C: Comments> {
+    transform: &'a TransformVisitor<C>,
+    captures: Captures,
+    in_jsx: bool,
+}
+
+impl<'a, C: Comments> LexicalCaptureVisitor<'a, C> {
+    pub fn new(transform: &'a TransformVisitor<C>) -> Self {
         Self {
-            this_id: None,
-            has_jsx: false,
+            transform,
+            captures: Captures::default(),
+            in_jsx: false,
         }
     }
+
+    /// Visits `node` as a scope of its own and returns what it captured.
+    fn scope<N: VisitMutWith<Self>>(&mut self, node: &mut N) -> Option<Stmt> {
+        let captures = std::mem::take(&mut self.captures);
+        let in_jsx = std::mem::replace(&mut self.in_jsx, false);
+        node.visit_mut_children_with(self);
+        self.in_jsx = in_jsx;
+        std::mem::replace(&mut self.captures, captures).declaration()
+    }
+
+    fn visit_mut_expr_scope(&mut self, expr: &mut Box<Expr>) {
+        if let Some(decl) = self.scope(&mut **expr) {
+            **expr = iife(vec![
+                decl,
+                Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(expr.take()),
+                }),
+            ]);
+        }
+    }
+
+    fn capture(slot: &mut Option<Ident>, name: &str) -> Expr {
+        Expr::Ident(
+            slot.get_or_insert_with(|| private_ident!(format!("_{}$", name)))
+                .clone(),
+        )
+    }
 }
 
-impl VisitMut for ThisBlockVisitor {
-    fn visit_mut_block_stmt(&mut self, block: &mut BlockStmt) {
-        let mut id = self.this_id.clone();
-        self.has_jsx = false;
-        block.visit_mut_children_with(self);
-        std::mem::swap(&mut self.this_id, &mut id);
-        if let Some(id) = id {
-            block.stmts.insert(
-                0,
-                Stmt::Decl(Decl::Var(Box::new(VarDecl {
+fn iife(stmts: Vec<Stmt>) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(Expr::Paren(ParenExpr {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Arrow(ArrowExpr {
+                span: DUMMY_SP,
+                params: vec![],
+                body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
                     span: DUMMY_SP,
-                    kind: VarDeclKind::Const,
-                    declare: false,
-                    decls: vec![VarDeclarator {
-                        span: DUMMY_SP,
-                        name: Pat::Ident(id.into()),
-                        init: Some(Box::new(Expr::This(ThisExpr { span: DUMMY_SP }))),
-                        definite: false,
-                    }],
-                }))),
-            )
+                    stmts,
+                })),
+                is_async: false,
+                is_generator: false,
+                type_params: None,
+                return_type: None,
+            })),
+        }))),
+        args: vec![],
+        type_args: None,
+    })
+}
+
+fn prepend_to_body(body: &mut Option<BlockStmt>, decl: Option<Stmt>) {
+    if let (Some(body), Some(decl)) = (body, decl) {
+        body.stmts.insert(0, decl);
+    }
+}
+
+impl<C: Comments> VisitMut for LexicalCaptureVisitor<'_, C> {
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        if let Some(decl) = self.scope(module) {
+            prepend_stmt(&mut module.body, ModuleItem::Stmt(decl));
         }
     }
+
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        let decl = self.scope(function);
+        prepend_to_body(&mut function.body, decl);
+    }
+
+    fn visit_mut_constructor(&mut self, constructor: &mut Constructor) {
+        let decl = self.scope(constructor);
+        if let (Some(body), Some(decl)) = (&mut constructor.body, decl) {
+            // `this` can't be read before `super()` returns
+            let index = body
+                .stmts
+                .iter()
+                .position(|stmt| {
+                    matches!(stmt, Stmt::Expr(ExprStmt { expr, .. })
+                        if matches!(&**expr, Expr::Call(CallExpr { callee: Callee::Super(_), .. })))
+                })
+                .map_or(0, |i| i + 1);
+            body.stmts.insert(index, decl);
+        }
+    }
+
+    fn visit_mut_getter_prop(&mut self, getter: &mut GetterProp) {
+        getter.key.visit_mut_with(self);
+        let decl = self.scope(&mut getter.body);
+        prepend_to_body(&mut getter.body, decl);
+    }
+
+    fn visit_mut_setter_prop(&mut self, setter: &mut SetterProp) {
+        let decl = self.scope(setter);
+        prepend_to_body(&mut setter.body, decl);
+    }
+
+    fn visit_mut_static_block(&mut self, block: &mut StaticBlock) {
+        if let Some(decl) = self.scope(block) {
+            block.body.stmts.insert(0, decl);
+        }
+    }
+
+    fn visit_mut_class_prop(&mut self, prop: &mut ClassProp) {
+        prop.key.visit_mut_with(self);
+        if let Some(value) = &mut prop.value {
+            self.visit_mut_expr_scope(value);
+        }
+    }
+
+    fn visit_mut_private_prop(&mut self, prop: &mut PrivateProp) {
+        if let Some(value) = &mut prop.value {
+            self.visit_mut_expr_scope(value);
+        }
+    }
+
+    fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+        if self.in_jsx {
+            arrow.visit_mut_children_with(self);
+            return;
+        }
+        let Some(decl) = self.scope(arrow) else {
+            return;
+        };
+        match &mut *arrow.body {
+            BlockStmtOrExpr::BlockStmt(block) => block.stmts.insert(0, decl),
+            BlockStmtOrExpr::Expr(expr) => {
+                *arrow.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![
+                        decl,
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(expr.take()),
+                        }),
+                    ],
+                })
+            }
+        }
+    }
+
     fn visit_mut_jsx_element(&mut self, el: &mut JSXElement) {
-        self.has_jsx = true;
+        let in_jsx = std::mem::replace(&mut self.in_jsx, true);
         el.visit_mut_children_with(self);
+        self.in_jsx = in_jsx;
     }
+
     fn visit_mut_jsx_fragment(&mut self, el: &mut JSXFragment) {
-        self.has_jsx = true;
+        let in_jsx = std::mem::replace(&mut self.in_jsx, true);
         el.visit_mut_children_with(self);
+        self.in_jsx = in_jsx;
+    }
+
+    fn visit_mut_tagged_tpl(&mut self, tpl: &mut TaggedTpl) {
+        if !self.transform.is_html_tag(&tpl.tag) {
+            tpl.visit_mut_children_with(self);
+            return;
+        }
+        let in_jsx = std::mem::replace(&mut self.in_jsx, true);
+        tpl.tpl.visit_mut_with(self);
+        self.in_jsx = in_jsx;
     }
+
     fn visit_mut_expr(&mut self, n: &mut Expr) {
-        if let Expr::This(_) = n {
-            if !self.has_jsx {
-                return;
-            }
-            if self.this_id.is_none() {
-                self.this_id = Some(private_ident!("_self$"));
-            }
-            *n = Expr::Ident(self.this_id.clone().unwrap());
-        } else {
+        if !self.in_jsx {
             n.visit_mut_children_with(self);
+            return;
+        }
+        match n {
+            Expr::This(_) => *n = Self::capture(&mut self.captures.this, "self"),
+            Expr::Ident(id) if &id.sym == "arguments" => {
+                *n = Self::capture(&mut self.captures.arguments, "arguments")
+            }
+            Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::NewTarget,
+                ..
+            }) => *n = Self::capture(&mut self.captures.new_target, "newTarget"),
+            _ => n.visit_mut_children_with(self),
         }
     }
 }
@@ -144,6 +489,7 @@ pub struct TransformInfo {
     pub fragment_child: bool,
     pub to_be_closed: Option<HashSet<String>>,
     pub do_not_escape: bool,
+    pub preserve_whitespace: bool,
 }
 
 impl<C> TransformVisitor<C>
@@ -175,8 +521,15 @@ where
             self.transform_fragment_children(&node.children, &mut results);
             return Some(results);
         } else if let JSXElementChild::JSXText(node) = node {
-            let text =
-                trim_whitespace(&html_escape::encode_text(&node.raw).replace('\u{a0}', "&nbsp;"));
+            let whitespace = if info.preserve_whitespace {
+                Whitespace::Preserve
+            } else {
+                self.config.whitespace
+            };
+            let text = normalize_whitespace(
+                &html_escape::encode_text(&self.jsx_text_value(node)).replace('\u{a0}', "&nbsp;"),
+                whitespace,
+            );
             if text.is_empty() {
                 return None;
             }
@@ -228,7 +581,7 @@ where
                     }
                     let mut expr = vec![];
                     if self.config.wrap_conditionals
-                        && self.config.generate != "ssr"
+                        && self.config.generate != Generate::Ssr
                         && (matches!(**exp, Expr::Bin(_)) || matches!(**exp, Expr::Cond(_)))
                     {
                         let result =
@@ -264,7 +617,7 @@ where
                     } else {
                         let mut flag = false;
                         if !info.component_child
-                            && (self.config.generate != "ssr" || info.fragment_child)
+                            && (self.config.generate != Generate::Ssr || info.fragment_child)
                         {
                             if let Expr::Call(CallExpr {
                                 callee: Callee::Expr(ref ex),
@@ -1,16 +1,20 @@
 use super::structs::TemplateInstantiation;
-use crate::TransformVisitor;
+use crate::{config::Whitespace, TransformVisitor};
 use convert_case::{Case, Converter};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashSet;
 use swc_atoms::{Atom, JsWord};
 use swc_core::{
-    common::{comments::Comments, iter::IdentifyLast, BytePos, Span, DUMMY_SP},
+    common::{
+        comments::{CommentKind, Comments},
+        errors::HANDLER,
+        iter::IdentifyLast,
+        BytePos, Span, Spanned, DUMMY_SP,
+    },
     ecma::{
         ast::*,
-        minifier::eval::EvalResult,
-        utils::{prepend_stmt, private_ident},
+        utils::{prepend_stmt, private_ident, quote_ident},
         visit::{Visit, VisitWith},
     },
 };
@@ -21,6 +25,74 @@ pub static RESERVED_NAME_SPACES: Lazy<HashSet<&str>> =
 static NON_SPREAD_NAME_SPACES: Lazy<HashSet<&str>> =
     Lazy::new(|| HashSet::from(["class", "style", "use", "prop", "attr"]));
 
+static NEWLINE_WHITESPACE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[\r\n]\s*$").unwrap());
+static BLANK_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*$").unwrap());
+static SPACES_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^ *$").unwrap());
+static LEADING_WHITESPACE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*").unwrap());
+static WHITESPACE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
+static BACKTICK_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"`").unwrap());
+
+/// Reports an error for `span` through the host's diagnostics handler, which
+/// fails the build once the file is transformed. Without a handler there is
+/// no other way to report it, so this panics.
+pub fn emit_error(span: Span, message: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.struct_span_err(span, message).emit());
+    } else {
+        panic!("{}", message);
+    }
+}
+
+/// Reports a warning for `span` through the host's diagnostics handler, when
+/// one is installed.
+pub fn emit_warning(span: Span, message: &str) {
+    if HANDLER.is_set() {
+        HANDLER.with(|handler| handler.struct_span_warn(span, message).emit());
+    }
+}
+
+/// Builds `window.appRoot` from its source text, which
+/// [Config::check](crate::config::Config::check) verified to be identifiers
+/// joined by dots.
+pub fn member_path(path: &str) -> Expr {
+    let mut segments = path.split('.').map(|segment| quote_ident!(segment.trim()));
+    let first = Expr::Ident(segments.next().unwrap());
+    segments.fold(first, |obj, prop| {
+        Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(obj),
+            prop: MemberProp::Ident(prop),
+        })
+    })
+}
+
+/// Undoes the doubling of line terminators in the raw value of JSX text,
+/// where each one is read as its normalized form followed by the original,
+/// so `\r\n` becomes `\n\r\n`.
+fn dedupe_line_terminators(raw: &str) -> String {
+    let mut text = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        text.push(c);
+        if !matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
+            continue;
+        }
+        let mut rest = chars.clone();
+        match (c, rest.next(), rest.next()) {
+            ('\n', Some('\r'), Some('\n')) => {
+                text.pop();
+                text.push_str("\r\n");
+                chars = rest;
+            }
+            (_, Some(next), _) if next == c => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    text
+}
+
 pub fn is_component(tag_name: &str) -> bool {
     let first_char = tag_name.chars().next().unwrap();
     let first_char_lower = first_char.to_lowercase().to_string();
@@ -29,6 +101,14 @@ pub fn is_component(tag_name: &str) -> bool {
     first_char_lower != first_char.to_string() || has_dot || has_non_alpha
 }
 
+/// The package of the runtime module, `solid-js` for `solid-js/web`, which
+/// exports the props helpers like `mergeProps`.
+pub fn core_module(module_name: &str) -> &str {
+    module_name
+        .rsplit_once('/')
+        .map_or(module_name, |(package, _)| package)
+}
+
 pub fn get_tag_name(element: &JSXElement) -> String {
     let jsx_name = &element.opening.name;
     match jsx_name {
@@ -63,10 +143,30 @@ where
             .clone()
     }
 
+    /// Returns a span carrying a `/*#__PURE__*/` comment, for calls that have
+    /// no side effects when the surrounding code is evaluated.
+    pub fn pure_span(&self) -> Span {
+        let span = Span::dummy_with_cmt();
+        self.comments.add_pure_comment(span.lo);
+        span
+    }
+
     pub fn insert_imports(&mut self, module: &mut Module) {
-        let mut entries = self.imports.drain().collect::<Vec<_>>();
-        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
-        for (name, val) in entries {
+        let core = core_module(&self.config.module_name).to_string();
+        let mut entries = self
+            .core_imports
+            .drain()
+            .map(|(name, local)| (core.clone(), name, local))
+            .collect::<Vec<_>>();
+        entries.sort_by(|(_, a, _), (_, b, _)| a.cmp(b));
+        let mut runtime = self
+            .imports
+            .drain()
+            .map(|(name, local)| (self.config.module_name.clone(), name, local))
+            .collect::<Vec<_>>();
+        runtime.sort_by(|(_, a, _), (_, b, _)| a.cmp(b));
+        entries.extend(runtime);
+        for (src, name, val) in entries {
             prepend_stmt(
                 &mut module.body,
                 ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
@@ -78,7 +178,7 @@ where
                     })],
                     src: Box::new(Str {
                         span: DUMMY_SP,
-                        value: self.config.module_name.clone().into(),
+                        value: src.into(),
                         raw: None,
                     }),
                     span: DUMMY_SP,
@@ -89,11 +189,53 @@ where
         }
     }
 
+    /// The value of JSX text, HTML entities decoded. The lexer reports every
+    /// line terminator of JSX text twice, so the value is read from the
+    /// source through the span whenever it is available, and otherwise
+    /// rebuilt from the raw text.
+    pub fn jsx_text_value(&self, text: &JSXText) -> Atom {
+        self.source_map
+            .as_ref()
+            .filter(|_| !text.span.is_dummy())
+            .and_then(|source_map| source_map.span_to_snippet(text.span).ok())
+            .map_or_else(
+                || dedupe_line_terminators(&text.raw).into(),
+                |source| html_escape::decode_html_entities(&source).into(),
+            )
+    }
+
+    /// Merges the options of the module's leading pragma comments over the
+    /// configuration, for this file only.
+    pub fn apply_config_pragmas(&mut self, module: &Module) {
+        let mut positions = vec![module.span.lo];
+        if let Some(item) = module.body.first() {
+            positions.push(item.span_lo());
+        }
+        positions.dedup();
+        for pos in positions {
+            for comment in self.comments.get_leading(pos).unwrap_or_default() {
+                if comment.kind != CommentKind::Block {
+                    continue;
+                }
+                for warning in self.config.apply_pragma(&comment.text) {
+                    emit_warning(comment.span, &warning);
+                }
+            }
+        }
+    }
+
+    /// Calls `delegateEvents` with the events the module delegates, or with
+    /// `exportDelegatedEvents` exports them instead, even when there are none.
     pub fn insert_events(&mut self, module: &mut Module) {
-        if !self.events.is_empty() {
-            let mut elems: Vec<_> = self.events.drain().collect();
-            elems.sort();
-            let elems = elems
+        let export = !self.config.export_delegated_events.is_empty();
+        if self.events.is_empty() && !export {
+            return;
+        }
+        let mut elems: Vec<_> = self.events.drain().collect();
+        elems.sort();
+        let events = Box::new(Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: elems
                 .into_iter()
                 .map(|v| {
                     Some(ExprOrSpread {
@@ -101,25 +243,95 @@ where
                         expr: Box::new(Expr::Lit(Lit::Str(v.into()))),
                     })
                 })
-                .collect();
-            module.body.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
-                span: DUMMY_SP,
-                expr: Box::new(Expr::Call(CallExpr {
+                .collect(),
+        }));
+        if export {
+            let name = &self.config.export_delegated_events;
+            if let Some(span) = self.find_module_name(module, name) {
+                emit_error(
+                    span,
+                    &format!(
+                        "`exportDelegatedEvents` exports `{}`, which the module already declares or exports",
+                        name
+                    ),
+                );
+                return;
+            }
+            module
+                .body
+                .push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                     span: DUMMY_SP,
-                    callee: Callee::Expr(Box::new(Expr::Ident(
-                        self.register_import_method("delegateEvents"),
-                    ))),
-                    args: vec![ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Array(ArrayLit {
+                    decl: Decl::Var(Box::new(VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Const,
+                        declare: false,
+                        decls: vec![VarDeclarator {
                             span: DUMMY_SP,
-                            elems,
-                        })),
-                    }],
-                    type_args: None,
-                })),
-            })))
+                            name: Pat::Ident(quote_ident!(name.clone()).into()),
+                            init: Some(events),
+                            definite: false,
+                        }],
+                    })),
+                })));
+            return;
         }
+        let mut args = vec![ExprOrSpread {
+            spread: None,
+            expr: events,
+        }];
+        if !self.config.delegation_target.is_empty() {
+            args.push(ExprOrSpread {
+                spread: None,
+                expr: Box::new(member_path(&self.config.delegation_target)),
+            });
+        }
+        module.body.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(
+                    self.register_import_method("delegateEvents"),
+                ))),
+                args,
+                type_args: None,
+            })),
+        })))
+    }
+
+    /// Where the module declares or exports `name`, if it does.
+    fn find_module_name(&self, module: &Module, name: &str) -> Option<Span> {
+        if let Some(id) = self
+            .binding_collector
+            .module_bindings
+            .iter()
+            .find(|id| id.0 == name)
+        {
+            let mut finder = IdentFinder {
+                id: id.clone(),
+                span: None,
+            };
+            module.visit_with(&mut finder);
+            return Some(finder.span.unwrap_or(DUMMY_SP));
+        }
+        module.body.iter().find_map(|item| {
+            let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) = item else {
+                return None;
+            };
+            export.specifiers.iter().find_map(|specifier| {
+                let exported = match specifier {
+                    ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) => {
+                        exported.as_ref().unwrap_or(orig)
+                    }
+                    ExportSpecifier::Namespace(ExportNamespaceSpecifier { name, .. }) => name,
+                    ExportSpecifier::Default(_) => return None,
+                };
+                let (sym, span) = match exported {
+                    ModuleExportName::Ident(id) => (&id.sym, id.span),
+                    ModuleExportName::Str(str) => (&str.value, str.span),
+                };
+                (**sym == *name).then_some(span)
+            })
+        })
     }
 
     pub fn transform_condition(
@@ -436,10 +648,7 @@ where
             }) => match **expr {
                 Expr::Lit(ref lit) => Some(lit_to_string(lit)),
                 Expr::Seq(_) => None,
-                _ => match self.evaluator.as_mut().unwrap().eval(expr) {
-                    Some(EvalResult::Lit(lit)) => Some(lit_to_string(&lit)),
-                    _ => None,
-                },
+                _ => self.evaluate(expr).map(|lit| lit_to_string(&lit)),
             },
             _ => None,
         }
@@ -495,9 +704,40 @@ where
         expr.visit_with(&mut dyn_visitor);
         dyn_visitor.dynamic
     }
+
+    /// Whether `node` creates JSX outside of nested functions.
+    pub fn contains_jsx<'a, N>(&'a self, node: &N) -> bool
+    where
+        N: VisitWith<DynamicVisitor<'a, C>>,
+    {
+        let mut dyn_visitor = DynamicVisitor {
+            _transform_visitor: self,
+            check_member: false,
+            check_tags: true,
+            check_call_expression: false,
+            dynamic: false,
+            is_stop: false,
+        };
+        node.visit_with(&mut dyn_visitor);
+        dyn_visitor.dynamic
+    }
+}
+
+/// Finds the first use of `id`, to point diagnostics at it.
+struct IdentFinder {
+    id: Id,
+    span: Option<Span>,
+}
+
+impl Visit for IdentFinder {
+    fn visit_ident(&mut self, n: &Ident) {
+        if self.span.is_none() && n.to_id() == self.id {
+            self.span = Some(n.span);
+        }
+    }
 }
 
-struct DynamicVisitor<'a, C>
+pub struct DynamicVisitor<'a, C>
 where
     C: Comments,
 {
@@ -605,11 +845,13 @@ where
     }
 }
 
-pub fn filter_children(c: &JSXElementChild) -> bool {
+pub fn filter_children(c: &JSXElementChild, whitespace: Whitespace) -> bool {
     match c {
         JSXElementChild::JSXText(t) => {
-            let regex = Regex::new(r"^[\r\n]\s*$").unwrap();
-            !regex.is_match(&t.raw)
+            if whitespace != Whitespace::Jsx {
+                return !t.raw.is_empty();
+            }
+            !NEWLINE_WHITESPACE_REGEX.is_match(&t.raw)
         }
         JSXElementChild::JSXExprContainer(JSXExprContainer {
             expr: JSXExpr::JSXEmptyExpr(_),
@@ -619,6 +861,24 @@ pub fn filter_children(c: &JSXElementChild) -> bool {
     }
 }
 
+/// Filters `children` like [`filter_children`], splicing the children of any
+/// nested fragment into the list in its place.
+pub fn flatten_children(
+    children: &[JSXElementChild],
+    whitespace: Whitespace,
+) -> Vec<&JSXElementChild> {
+    children
+        .iter()
+        .filter(|c| filter_children(c, whitespace))
+        .flat_map(|c| match c {
+            JSXElementChild::JSXFragment(fragment) => {
+                flatten_children(&fragment.children, whitespace)
+            }
+            _ => vec![c],
+        })
+        .collect()
+}
+
 pub fn convert_jsx_identifier(attr_name: &JSXAttrName) -> (PropName, String) {
     let name = match &attr_name {
         JSXAttrName::Ident(ident) => ident.sym.to_string(),
@@ -642,7 +902,7 @@ pub fn convert_jsx_identifier(attr_name: &JSXAttrName) -> (PropName, String) {
     }
 }
 
-pub fn check_length(children: &Vec<&JSXElementChild>) -> bool {
+pub fn check_length(children: &Vec<&JSXElementChild>, whitespace: Whitespace) -> bool {
     let mut i = 0;
     for child in children {
         if !matches!(
@@ -653,8 +913,9 @@ pub fn check_length(children: &Vec<&JSXElementChild>) -> bool {
             })
         ) {
             if let JSXElementChild::JSXText(t) = child {
-                if !Regex::new(r"^\s*$").unwrap().is_match(&t.raw)
-                    || Regex::new(r"^ *$").unwrap().is_match(&t.raw)
+                if whitespace != Whitespace::Jsx
+                    || !BLANK_REGEX.is_match(&t.raw)
+                    || SPACES_REGEX.is_match(&t.raw)
                 {
                     i += 1;
                 }
@@ -669,26 +930,30 @@ pub fn check_length(children: &Vec<&JSXElementChild>) -> bool {
 pub fn trim_whitespace(text: &str) -> String {
     let mut text = text.replace('\r', "");
     if text.contains('\n') {
-        let start_space_regex = Regex::new(r"^\s*").unwrap();
-        let space_regex = Regex::new(r"^\s*$").unwrap();
         text = text
             .split('\n')
             .enumerate()
             .map(|(i, t)| {
                 if i > 0 {
-                    start_space_regex.replace_all(t, "").to_string()
+                    LEADING_WHITESPACE_REGEX.replace_all(t, "").to_string()
                 } else {
                     String::from(t)
                 }
             })
-            .filter(|s| !space_regex.is_match(s))
+            .filter(|s| !BLANK_REGEX.is_match(s))
             .reduce(|cur, nxt| format!("{} {}", cur, nxt))
             .unwrap_or("".to_owned());
     }
-    return Regex::new(r"\s+")
-        .unwrap()
-        .replace_all(&text, " ")
-        .to_string();
+    return WHITESPACE_REGEX.replace_all(&text, " ").to_string();
+}
+
+/// Normalizes template text according to the `whitespace` mode.
+pub fn normalize_whitespace(text: &str, whitespace: Whitespace) -> String {
+    match whitespace {
+        Whitespace::Jsx => trim_whitespace(text),
+        Whitespace::Preserve => text.replace("\r\n", "\n").replace('\r', "\n"),
+        Whitespace::CollapseHtml => WHITESPACE_REGEX.replace_all(text, " ").to_string(),
+    }
 }
 
 pub fn to_property_name(name: &str) -> String {
@@ -730,10 +995,7 @@ pub fn wrapped_by_text(list: &Vec<TemplateInstantiation>, start_index: usize) ->
 }
 
 pub fn escape_backticks(value: &str) -> String {
-    Regex::new(r"`")
-        .unwrap()
-        .replace_all(value, r"\`")
-        .to_string()
+    BACKTICK_REGEX.replace_all(value, r"\`").to_string()
 }
 
 pub fn escape_html(s: &str, attr: bool) -> String {
@@ -837,13 +1099,56 @@ pub fn lit_to_string(lit: &Lit) -> String {
         Lit::Str(value) => value.value.to_string(),
         Lit::Bool(value) => value.value.to_string(),
         Lit::Null(_) => "null".to_string(),
-        Lit::Num(value) => value.value.to_string(),
+        Lit::Num(value) => number_to_string(value.value),
         Lit::BigInt(value) => value.value.to_string(),
         Lit::Regex(value) => value.exp.to_string(),
         Lit::JSXText(value) => value.raw.to_string(),
     }
 }
 
+/// Formats a number the way JavaScript's `Number.prototype.toString` does,
+/// which differs from Rust for `-0`, `NaN`, infinities and for magnitudes that
+/// JavaScript prints in exponent notation.
+pub fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    // `{:e}` yields the shortest round-tripping digits, e.g. `-1.25e-7`
+    let formatted = format!("{:e}", value.abs());
+    let (mantissa, exponent) = formatted.split_once('e').unwrap();
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().unwrap() + 1;
+    let unsigned = if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat(-n as usize), digits)
+    } else {
+        let (first, rest) = digits.split_at(1);
+        format!(
+            "{}{}{}e{}{}",
+            first,
+            if rest.is_empty() { "" } else { "." },
+            rest,
+            if n > 0 { "+" } else { "-" },
+            (n - 1).abs()
+        )
+    };
+    if value < 0.0 {
+        format!("-{}", unsigned)
+    } else {
+        unsigned
+    }
+}
+
 pub fn is_l_val(expr: &Expr) -> bool {
     matches!(
         expr,
@@ -883,11 +1188,22 @@ pub fn is_binary_expression(expr: &Expr) -> bool {
     false
 }
 
+/// Converts JSX text into a string literal value according to the `whitespace` mode.
+pub fn text_to_str(t: &Atom, whitespace: Whitespace) -> JsWord {
+    match whitespace {
+        Whitespace::Jsx => jsx_text_to_str(t),
+        whitespace => normalize_whitespace(t, whitespace).into(),
+    }
+}
+
 pub fn jsx_text_to_str(t: &Atom) -> JsWord {
     let mut buf = String::new();
-    let replaced = t.replace('\t', " ");
+    let replaced = t
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .replace('\t', " ");
 
-    for (is_last, (i, line)) in replaced.lines().enumerate().identify_last() {
+    for (is_last, (i, line)) in replaced.split('\n').enumerate().identify_last() {
         if line.is_empty() {
             continue;
         }
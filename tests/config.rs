@@ -0,0 +1,113 @@
+use jsx_dom_expressions::config::{Config, Generate};
+
+#[test]
+fn parses_options() {
+    let config = Config::from_json(r#"{"moduleName": "r-dom", "generate": "ssr"}"#).unwrap();
+    assert_eq!(config.module_name, "r-dom");
+    assert_eq!(config.generate, Generate::Ssr);
+    assert!(config.delegate_events);
+}
+
+#[test]
+fn suggests_misspelled_options() {
+    let error = Config::from_json(r#"{"moduleNmae": "r-dom"}"#)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error,
+        "Unknown option `moduleNmae`, did you mean `moduleName`?"
+    );
+    let error = Config::from_json(r#"{"somethingElse": true}"#)
+        .err()
+        .unwrap();
+    assert_eq!(error, "Unknown option `somethingElse`");
+}
+
+#[test]
+fn rejects_invalid_values() {
+    let error = Config::from_json(r#"{"generate": "SSR"}"#).err().unwrap();
+    assert!(error.contains("unknown variant `SSR`"), "{}", error);
+    let error = Config::from_json(r#"{"hydratable": "yes"}"#).err().unwrap();
+    assert!(error.contains("expected a boolean"), "{}", error);
+}
+
+#[test]
+fn rejects_conflicting_options() {
+    assert!(Config::from_json(r#"{"hydratable": true, "generate": "universal"}"#).is_err());
+    assert!(Config::from_json(r#"{"delegateEvents": false, "delegationTarget": "root"}"#).is_err());
+    assert!(Config::from_json(r#"{"delegationTarget": "window.app-root"}"#).is_err());
+    assert!(Config::from_json(r#"{"delegationTarget": "window."}"#).is_err());
+    let error = Config::from_json(r#"{"exportDelegatedEvents": "my-events"}"#)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error,
+        "`exportDelegatedEvents` must be an identifier, found `my-events`"
+    );
+    assert!(Config::from_json(r#"{"exportDelegatedEvents": "default"}"#).is_err());
+}
+
+#[test]
+fn pragma_overrides_options() {
+    let mut config = Config::default();
+    let warnings = config
+        .apply_pragma("* @jsxDomExpressions generate=ssr delegateEvents=false moduleNmae=r-dom");
+    assert_eq!(config.generate, Generate::Ssr);
+    assert!(!config.delegate_events);
+    assert_eq!(
+        warnings,
+        ["@jsxDomExpressions: Unknown option `moduleNmae`, did you mean `moduleName`?"]
+    );
+
+    let warnings = config.apply_pragma("@jsxDomExpressions hydratable=true generate=universal");
+    assert!(!config.hydratable);
+    assert_eq!(config.generate, Generate::Ssr);
+    assert_eq!(
+        warnings,
+        ["@jsxDomExpressions: `hydratable` is not supported with `generate: \"universal\"`, the options were ignored"]
+    );
+}
+
+#[test]
+fn pragma_rejects_invalid_options() {
+    let mut config = Config::default();
+    let warnings =
+        config.apply_pragma("@jsxDomExpressions hydratable generate=SSR moduleName=r-dom");
+    assert_eq!(config.generate, Generate::Dom);
+    assert_eq!(config.module_name, "r-dom");
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+        warnings[0],
+        "@jsxDomExpressions: expected `key=value`, found `hydratable`"
+    );
+    assert!(
+        warnings[1].starts_with(
+            "@jsxDomExpressions: invalid value `SSR` for `generate`, unknown variant `SSR`"
+        ),
+        "{}",
+        warnings[1]
+    );
+
+    let warnings =
+        config.apply_pragma("@jsxDomExpressions delegateEvents=false exportDelegatedEvents=events");
+    assert!(config.delegate_events);
+    assert_eq!(
+        warnings,
+        ["@jsxDomExpressions: `delegationTarget` and `exportDelegatedEvents` require `delegateEvents`, the options were ignored"]
+    );
+}
+
+/// Regenerates `config.schema.json` with `UPDATE=1`.
+#[test]
+fn schema_is_up_to_date() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/config.schema.json");
+    let schema = Config::json_schema();
+    if std::env::var("UPDATE").is_ok() {
+        std::fs::write(path, &schema).unwrap();
+    }
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        schema,
+        "config.schema.json is out of date, run `UPDATE=1 cargo test --test config`"
+    );
+}
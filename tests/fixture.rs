@@ -1,15 +1,59 @@
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
-use jsx_dom_expressions::config::Config;
+use jsx_dom_expressions::config::{Config, Whitespace};
 use jsx_dom_expressions::TransformVisitor;
-use swc_core::common::{chain, Mark};
+use swc_core::common::errors::{DiagnosticBuilder, Emitter, Handler, HandlerFlags, HANDLER};
+use swc_core::common::{chain, sync::Lrc, Mark, SourceMap};
 use swc_core::{
+    ecma::ast::Module,
     ecma::parser::{EsConfig, Syntax},
     ecma::transforms::base::resolver,
     ecma::transforms::testing::test_fixture,
-    ecma::visit::as_folder,
+    ecma::visit::{as_folder, Fold},
 };
-use testing::fixture;
+use testing::{fixture, NormalizedOutput};
+
+/// Records the warnings of a transform as `warning: message at line:column`,
+/// which the default test handler would drop.
+#[derive(Clone, Default)]
+struct Warnings(Arc<Mutex<Vec<String>>>);
+
+struct WarningEmitter {
+    cm: Lrc<SourceMap>,
+    warnings: Warnings,
+}
+
+impl Emitter for WarningEmitter {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        let position = db
+            .span
+            .primary_span()
+            .filter(|span| !span.is_dummy())
+            .map(|span| {
+                let loc = self.cm.lookup_char_pos(span.lo);
+                format!(" at {}:{}", loc.line, loc.col_display + 1)
+            })
+            .unwrap_or_default();
+        self.warnings
+            .0
+            .lock()
+            .unwrap()
+            .push(format!("{}: {}{}", db.level, db.message(), position));
+    }
+}
+
+/// Runs `inner` with a handler that lets warnings through to `warnings`.
+struct CaptureWarnings<F> {
+    handler: Handler,
+    inner: F,
+}
+
+impl<F: Fold> Fold for CaptureWarnings<F> {
+    fn fold_module(&mut self, module: Module) -> Module {
+        HANDLER.set(&self.handler, || self.inner.fold_module(module))
+    }
+}
 
 fn syntax() -> Syntax {
     Syntax::Es(EsConfig {
@@ -18,28 +62,193 @@ fn syntax() -> Syntax {
     })
 }
 
-#[fixture("tests/fixture/babel/**/code.js")]
-fn jsx_dom_expressions_fixture_babel(input: PathBuf) {
+fn babel_config() -> Config {
+    Config {
+        module_name: "r-dom".to_string(),
+        built_ins: vec!["For".to_string(), "Show".to_string()],
+        context_to_custom_elements: true,
+        ..Default::default()
+    }
+}
+
+/// Compares the transformed `code.js` to `output.js`, and the warnings it
+/// emits to `output.stderr`, which is left out when there are none.
+fn run_fixture(input: PathBuf, config: impl Fn() -> Config) {
+    run_fixture_with(input, config, true);
+}
+
+/// Like [run_fixture], optionally without giving the transform the source
+/// of the file.
+fn run_fixture_with(input: PathBuf, config: impl Fn() -> Config, source_map: bool) {
     let output = input.parent().unwrap().join("output.js");
+    let warnings = Warnings::default();
 
     test_fixture(
         syntax(),
         &|t| {
-            chain!(
-                resolver(Mark::new(), Mark::new(), false),
-                as_folder(TransformVisitor::new(
-                    Config {
-                        module_name: "r-dom".to_string(),
-                        built_ins: vec!["For".to_string(), "Show".to_string()],
-                        context_to_custom_elements: true,
+            let unresolved_mark = Mark::new();
+            CaptureWarnings {
+                handler: Handler::with_emitter_and_flags(
+                    Box::new(WarningEmitter {
+                        cm: t.cm.clone(),
+                        warnings: warnings.clone(),
+                    }),
+                    HandlerFlags {
+                        can_emit_warnings: true,
                         ..Default::default()
                     },
-                    t.comments.clone()
-                ))
-            )
+                ),
+                inner: chain!(
+                    resolver(unresolved_mark, Mark::new(), false),
+                    as_folder({
+                        let visitor =
+                            TransformVisitor::new(config(), t.comments.clone(), unresolved_mark);
+                        if source_map {
+                            visitor.with_source_map(t.cm.clone())
+                        } else {
+                            visitor
+                        }
+                    })
+                ),
+            }
         },
         &input,
         &output,
         Default::default(),
     );
+
+    let warnings = warnings.0.lock().unwrap().join("\n");
+    NormalizedOutput::from(warnings)
+        .compare_to_file(output.with_extension("stderr"))
+        .unwrap();
+}
+
+#[fixture("tests/fixture/babel/**/code.js")]
+fn jsx_dom_expressions_fixture_babel(input: PathBuf) {
+    run_fixture(input, babel_config);
+}
+
+#[fixture("tests/fixture/dom/**/code.js")]
+fn jsx_dom_expressions_fixture_dom(input: PathBuf) {
+    run_fixture(input, babel_config);
+}
+
+#[fixture("tests/fixture/whitespace/preserve/code.js")]
+fn jsx_dom_expressions_fixture_whitespace_preserve(input: PathBuf) {
+    run_fixture(input, || Config {
+        whitespace: Whitespace::Preserve,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/whitespace/preserve/code.js")]
+fn jsx_dom_expressions_fixture_whitespace_preserve_without_source(input: PathBuf) {
+    run_fixture_with(
+        input,
+        || Config {
+            whitespace: Whitespace::Preserve,
+            ..babel_config()
+        },
+        false,
+    );
+}
+
+#[fixture("tests/fixture/whitespace/collapse-html/code.js")]
+fn jsx_dom_expressions_fixture_whitespace_collapse_html(input: PathBuf) {
+    run_fixture(input, || Config {
+        whitespace: Whitespace::CollapseHtml,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/optimize-walks/code.js")]
+fn jsx_dom_expressions_fixture_optimize_walks(input: PathBuf) {
+    run_fixture(input, || Config {
+        optimize_walks: true,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/lazy-templates/code.js")]
+fn jsx_dom_expressions_fixture_lazy_templates(input: PathBuf) {
+    run_fixture(input, || Config {
+        lazy_templates: true,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/react-compat/code.js")]
+fn jsx_dom_expressions_fixture_react_compat(input: PathBuf) {
+    run_fixture(input, || Config {
+        react_compat: true,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/html-template/code.js")]
+fn jsx_dom_expressions_fixture_html_template(input: PathBuf) {
+    run_fixture(input, || Config {
+        html_tag: "html".to_string(),
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/custom-elements-manifest/code.js")]
+fn jsx_dom_expressions_fixture_custom_elements_manifest(input: PathBuf) {
+    let manifest = input.parent().unwrap().join("custom-elements.json");
+    run_fixture(input, || Config {
+        custom_elements_manifest: Some(
+            serde_json::from_str(&std::fs::read_to_string(&manifest).unwrap()).unwrap(),
+        ),
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/asset-urls/code.js")]
+fn jsx_dom_expressions_fixture_asset_urls(input: PathBuf) {
+    run_fixture(input, || Config {
+        transform_asset_urls: true,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/map-children-to-for/code.js")]
+fn jsx_dom_expressions_fixture_map_children_to_for(input: PathBuf) {
+    run_fixture(input, || Config {
+        map_children_to_for: true,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/destructured-props/code.js")]
+fn jsx_dom_expressions_fixture_destructured_props(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "solid-js/web".to_string(),
+        transform_destructured_props: true,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/hoist-event-handlers/**/code.js")]
+fn jsx_dom_expressions_fixture_hoist_event_handlers(input: PathBuf) {
+    run_fixture(input, || Config {
+        hoist_event_handlers: true,
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/delegation-target/**/code.js")]
+fn jsx_dom_expressions_fixture_delegation_target(input: PathBuf) {
+    run_fixture(input, || Config {
+        delegation_target: "window.appRoot".to_string(),
+        ..babel_config()
+    });
+}
+
+#[fixture("tests/fixture/export-delegated-events/**/code.js")]
+fn jsx_dom_expressions_fixture_export_delegated_events(input: PathBuf) {
+    run_fixture(input, || Config {
+        export_delegated_events: "delegatedEvents".to_string(),
+        ..babel_config()
+    });
 }
@@ -0,0 +1,13 @@
+const template = (
+  <div>
+    <img src="./logo.png" alt="Logo" />
+    <img src="https://example.com/remote.png" />
+    <img src="/public/absolute.png" />
+    <img srcset="./small.png 1x, ./large.png 2x, /static/huge.png 3x" src="./small.png" />
+    <video src="../media/intro.mp4" poster="./poster.jpg" />
+    <svg>
+      <use href="./icons.svg#close" />
+    </svg>
+    <a href="./docs.pdf">Docs</a>
+  </div>
+);
@@ -0,0 +1,19 @@
+import { template as _$template } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import _asset$ from "./logo.png";
+import _asset$2 from "./small.png";
+import _asset$3 from "./large.png";
+import _asset$4 from "../media/intro.mp4";
+import _asset$5 from "./poster.jpg";
+import _asset$6 from "./icons.svg";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div><img alt="Logo"><img src="https://example.com/remote.png"><img src="/public/absolute.png"><img><video></video><svg><use></use></svg><a href="./docs.pdf">Docs`);
+const template = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling, _el$4 = _el$3.nextSibling, _el$5 = _el$4.nextSibling, _el$6 = _el$5.nextSibling, _el$7 = _el$6.nextSibling, _el$8 = _el$7.firstChild;
+    _$setAttribute(_el$2, "src", _asset$);
+    _$setAttribute(_el$5, "srcset", `${_asset$2} 1x, ${_asset$3} 2x, /static/huge.png 3x`);
+    _$setAttribute(_el$5, "src", _asset$2);
+    _$setAttribute(_el$6, "src", _asset$4);
+    _$setAttribute(_el$6, "poster", _asset$5);
+    _$setAttribute(_el$8, "href", `${_asset$6}#close`);
+    return _el$;
+})();
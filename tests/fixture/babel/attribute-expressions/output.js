@@ -11,7 +11,7 @@ import { delegateEvents as _$delegateEvents } from "r-dom";
 import { className as _$className } from "r-dom";
 import { classList as _$classList } from "r-dom";
 import { addEventListener as _$addEventListener } from "r-dom";
-const _tmpl$ = /*#__PURE__*/ _$template(`<div id="main"><h1 class="base" id="my-h1"><a href="/">Welcome`), _tmpl$2 = /*#__PURE__*/ _$template(`<div><div></div><div> </div><div>`), _tmpl$3 = /*#__PURE__*/ _$template(`<div foo>`), _tmpl$4 = /*#__PURE__*/ _$template(`<div>`), _tmpl$5 = /*#__PURE__*/ _$template(`<div class="a b">`), _tmpl$6 = /*#__PURE__*/ _$template(`<input type="checkbox">`), _tmpl$7 = /*#__PURE__*/ _$template(`<div class="\`a">\`$\``), _tmpl$8 = /*#__PURE__*/ _$template(`<button class="static hi" type="button">Write`), _tmpl$9 = /*#__PURE__*/ _$template(`<button class="a b c">Hi`), _tmpl$10 = /*#__PURE__*/ _$template(`<div class="bg-red-500 flex flex-col">`), _tmpl$11 = /*#__PURE__*/ _$template(`<div><input readonly=""><input>`), _tmpl$12 = /*#__PURE__*/ _$template(`<div data="&quot;hi&quot;" data2="&quot;">`), _tmpl$13 = /*#__PURE__*/ _$template(`<a>`), _tmpl$14 = /*#__PURE__*/ _$template(`<div><a>`), _tmpl$15 = /*#__PURE__*/ _$template(`<div start="Hi">Hi`), _tmpl$16 = /*#__PURE__*/ _$template(`<label><span>Input is </span><input><div>`), _tmpl$17 = /*#__PURE__*/ _$template(`<div class="class1 class2 class3 class4 class5 class6" style="color:red;background-color:blue !important;border:1px solid black;font-size:12px;" random="random1 random2
+const _tmpl$ = /*#__PURE__*/ _$template(`<div id="main"><h1 class="base"><a href="/">Welcome`), _tmpl$2 = /*#__PURE__*/ _$template(`<div><div></div><div> </div><div>`), _tmpl$3 = /*#__PURE__*/ _$template(`<div foo>`), _tmpl$4 = /*#__PURE__*/ _$template(`<div>`), _tmpl$5 = /*#__PURE__*/ _$template(`<div class="a b">`), _tmpl$6 = /*#__PURE__*/ _$template(`<input type="checkbox">`), _tmpl$7 = /*#__PURE__*/ _$template(`<div class="\`a">\`$\``), _tmpl$8 = /*#__PURE__*/ _$template(`<button class="static hi" type="button">Write`), _tmpl$9 = /*#__PURE__*/ _$template(`<button class="a b c">Hi`), _tmpl$10 = /*#__PURE__*/ _$template(`<div class="bg-red-500 flex flex-col">`), _tmpl$11 = /*#__PURE__*/ _$template(`<div><input readonly=""><input>`), _tmpl$12 = /*#__PURE__*/ _$template(`<div data="&quot;hi&quot;" data2="&quot;">`), _tmpl$13 = /*#__PURE__*/ _$template(`<a>`), _tmpl$14 = /*#__PURE__*/ _$template(`<div><a>`), _tmpl$15 = /*#__PURE__*/ _$template(`<div start="Hi">Hi`), _tmpl$16 = /*#__PURE__*/ _$template(`<label><span>Input is </span><input><div>`), _tmpl$17 = /*#__PURE__*/ _$template(`<div class="class1 class2 class3 class4 class5 class6" style="color:red;background-color:blue !important;border:1px solid black;font-size:12px;" random="random1 random2
     random3 random4">`);
 const selected = true;
 let id = "my-h1";
@@ -26,6 +26,7 @@ const template = (()=>{
             color
         }
     }), false, true);
+    _$setAttribute(_el$2, "id", id);
     _$spread(_el$2, _$mergeProps(results, {
         foo: "",
         disabled: true,
@@ -127,12 +128,12 @@ const template11 = (()=>{
     _$use(something, _el$17, ()=>true);
     return _el$17;
 })();
-const template12 = (()=>{
+const template12 = /*#__PURE__*/ (()=>{
     const _el$18 = _tmpl$4();
     _el$18.htmlFor = thing;
     return _el$18;
 })();
-const template13 = (()=>{
+const template13 = /*#__PURE__*/ (()=>{
     const _el$19 = _tmpl$6();
     _el$19.checked = true;
     return _el$19;
@@ -29,7 +29,7 @@ const template = (props1)=>{
     const { content  } = props1;
     return (()=>{
         const _el$4 = _tmpl$2();
-        _$insert(_el$4, _$createComponent(Child, _$mergeProps({
+        _$insert(_el$4, /*#__PURE__*/ _$createComponent(Child, _$mergeProps({
             name: "John"
         }, props1, {
             ref (r$) {
@@ -41,7 +41,7 @@ const template = (props1)=>{
                 return _tmpl$3();
             }
         })), null);
-        _$insert(_el$4, _$createComponent(Child, _$mergeProps({
+        _$insert(_el$4, /*#__PURE__*/ _$createComponent(Child, _$mergeProps({
             name: "Jason"
         }, dynamicSpread, {
             ref (r$) {
@@ -54,7 +54,7 @@ const template = (props1)=>{
                 return _el$6;
             }
         })), null);
-        _$insert(_el$4, _$createComponent(Context.Consumer, {
+        _$insert(_el$4, /*#__PURE__*/ _$createComponent(Context.Consumer, {
             ref (r$) {
                 const _ref$4 = props1.consumerRef();
                 typeof _ref$4 === "function" && _ref$4(r$);
@@ -64,7 +64,7 @@ const template = (props1)=>{
         return _el$4;
     })();
 };
-const template2 = _$createComponent(Child, {
+const template2 = /*#__PURE__*/ _$createComponent(Child, {
     name: "Jake",
     get dynamic () {
         return state.data;
@@ -76,7 +76,7 @@ const template2 = _$createComponent(Child, {
     },
     ref: (el)=>e = el
 });
-const template3 = _$createComponent(Child, {
+const template3 = /*#__PURE__*/ _$createComponent(Child, {
     get children () {
         return [
             _tmpl$2(),
@@ -87,13 +87,13 @@ const template3 = _$createComponent(Child, {
     }
 });
 const [s, set] = createSignal();
-const template4 = _$createComponent(Child, {
+const template4 = /*#__PURE__*/ _$createComponent(Child, {
     ref: set,
     get children () {
         return _tmpl$2();
     }
 });
-const template5 = _$createComponent(Child, {
+const template5 = /*#__PURE__*/ _$createComponent(Child, {
     get dynamic () {
         return state.dynamic;
     },
@@ -102,21 +102,21 @@ const template5 = _$createComponent(Child, {
     }
 });
 // builtIns
-const template6 = _$createComponent(_$For, {
+const template6 = /*#__PURE__*/ _$createComponent(_$For, {
     get each () {
         return state.list;
     },
     get fallback () {
-        return _$createComponent(Loading, {});
+        return /*#__PURE__*/ _$createComponent(Loading, {});
     },
-    children: (item)=>_$createComponent(Show, {
+    children: (item)=>/*#__PURE__*/ _$createComponent(Show, {
             get when () {
                 return state.condition;
             },
             children: item
         })
 });
-const template7 = _$createComponent(Child, {
+const template7 = /*#__PURE__*/ _$createComponent(Child, {
     get children () {
         return [
             _tmpl$2(),
@@ -124,7 +124,7 @@ const template7 = _$createComponent(Child, {
         ];
     }
 });
-const template8 = _$createComponent(Child, {
+const template8 = /*#__PURE__*/ _$createComponent(Child, {
     get children () {
         return [
             (item)=>item,
@@ -132,59 +132,59 @@ const template8 = _$createComponent(Child, {
         ];
     }
 });
-const template9 = _$createComponent(_garbage, {
+const template9 = /*#__PURE__*/ _$createComponent(_garbage, {
     children: "Hi"
 });
 const template10 = (()=>{
     const _el$12 = _tmpl$4(), _el$13 = _el$12.firstChild, _el$18 = _el$13.nextSibling, _el$14 = _el$18.nextSibling, _el$19 = _el$14.nextSibling, _el$15 = _el$19.nextSibling, _el$20 = _el$15.nextSibling, _el$16 = _el$20.nextSibling, _el$21 = _el$16.nextSibling, _el$17 = _el$21.nextSibling;
-    _$insert(_el$12, _$createComponent(Link, {
+    _$insert(_el$12, /*#__PURE__*/ _$createComponent(Link, {
         children: "new"
     }), _el$13);
-    _$insert(_el$12, _$createComponent(Link, {
+    _$insert(_el$12, /*#__PURE__*/ _$createComponent(Link, {
         children: "comments"
     }), _el$18);
-    _$insert(_el$12, _$createComponent(Link, {
+    _$insert(_el$12, /*#__PURE__*/ _$createComponent(Link, {
         children: "show"
     }), _el$19);
-    _$insert(_el$12, _$createComponent(Link, {
+    _$insert(_el$12, /*#__PURE__*/ _$createComponent(Link, {
         children: "ask"
     }), _el$20);
-    _$insert(_el$12, _$createComponent(Link, {
+    _$insert(_el$12, /*#__PURE__*/ _$createComponent(Link, {
         children: "jobs"
     }), _el$21);
-    _$insert(_el$12, _$createComponent(Link, {
+    _$insert(_el$12, /*#__PURE__*/ _$createComponent(Link, {
         children: "submit"
     }), null);
     return _el$12;
 })();
 const template11 = (()=>{
     const _el$22 = _tmpl$5(), _el$23 = _el$22.firstChild, _el$26 = _el$23.nextSibling, _el$24 = _el$26.nextSibling, _el$27 = _el$24.nextSibling, _el$25 = _el$27.nextSibling;
-    _$insert(_el$22, _$createComponent(Link, {
+    _$insert(_el$22, /*#__PURE__*/ _$createComponent(Link, {
         children: "new"
     }), _el$23);
-    _$insert(_el$22, _$createComponent(Link, {
+    _$insert(_el$22, /*#__PURE__*/ _$createComponent(Link, {
         children: "comments"
     }), _el$26);
-    _$insert(_el$22, _$createComponent(Link, {
+    _$insert(_el$22, /*#__PURE__*/ _$createComponent(Link, {
         children: "show"
     }), _el$26);
-    _$insert(_el$22, _$createComponent(Link, {
+    _$insert(_el$22, /*#__PURE__*/ _$createComponent(Link, {
         children: "ask"
     }), _el$27);
-    _$insert(_el$22, _$createComponent(Link, {
+    _$insert(_el$22, /*#__PURE__*/ _$createComponent(Link, {
         children: "jobs"
     }), _el$27);
-    _$insert(_el$22, _$createComponent(Link, {
+    _$insert(_el$22, /*#__PURE__*/ _$createComponent(Link, {
         children: "submit"
     }), null);
     return _el$22;
 })();
 const template12 = (()=>{
     const _el$28 = _tmpl$6(), _el$29 = _el$28.firstChild, _el$34 = _el$29.nextSibling, _el$30 = _el$34.nextSibling, _el$35 = _el$30.nextSibling, _el$33 = _el$35.nextSibling;
-    _$insert(_el$28, _$createComponent(Link, {
+    _$insert(_el$28, /*#__PURE__*/ _$createComponent(Link, {
         children: "comments"
     }), _el$34);
-    _$insert(_el$28, _$createComponent(Link, {
+    _$insert(_el$28, /*#__PURE__*/ _$createComponent(Link, {
         children: "show"
     }), _el$35);
     return _el$28;
@@ -192,13 +192,13 @@ const template12 = (()=>{
 class Template13 {
     render() {
         const _self$ = this;
-        _$createComponent(Component, {
+        /*#__PURE__*/ _$createComponent(Component, {
             get prop () {
                 return _self$.something;
             },
             onClick: ()=>_self$.shouldStay,
             get children () {
-                return _$createComponent(Nested, {
+                return /*#__PURE__*/ _$createComponent(Nested, {
                     get prop () {
                         return _self$.data;
                     },
@@ -210,16 +210,16 @@ class Template13 {
         });
     }
 }
-const Template14 = _$createComponent(Component, {
+const Template14 = /*#__PURE__*/ _$createComponent(Component, {
     get children () {
         return data();
     }
 });
-const Template15 = _$createComponent(Component, props);
-const Template16 = _$createComponent(Component, _$mergeProps({
+const Template15 = /*#__PURE__*/ _$createComponent(Component, props);
+const Template16 = /*#__PURE__*/ _$createComponent(Component, _$mergeProps({
     something: something
 }, props));
-const Template17 = _$createComponent(Pre, {
+const Template17 = /*#__PURE__*/ _$createComponent(Pre, {
     get children () {
         return [
             _tmpl$7(),
@@ -230,7 +230,7 @@ const Template17 = _$createComponent(Pre, {
         ];
     }
 });
-const Template18 = _$createComponent(Pre, {
+const Template18 = /*#__PURE__*/ _$createComponent(Pre, {
     get children () {
         return [
             _tmpl$7(),
@@ -239,25 +239,25 @@ const Template18 = _$createComponent(Pre, {
         ];
     }
 });
-const Template19 = _$createComponent(Component, _$mergeProps(()=>s.dynamic()));
-const Template20 = _$createComponent(Component, {
+const Template19 = /*#__PURE__*/ _$createComponent(Component, _$mergeProps(()=>s.dynamic()));
+const Template20 = /*#__PURE__*/ _$createComponent(Component, {
     get "class" () {
         return prop.red ? "red" : "green";
     }
 });
-const template21 = _$createComponent(Component, _$mergeProps(()=>({
+const template21 = /*#__PURE__*/ _$createComponent(Component, _$mergeProps(()=>({
         get [key()] () {
             return props.value;
         }
     })));
-const template22 = _$createComponent(Component, {
+const template22 = /*#__PURE__*/ _$createComponent(Component, {
     get passObject () {
         return {
             ...a
         };
     }
 });
-const template23 = _$createComponent(Component, {
+const template23 = /*#__PURE__*/ _$createComponent(Component, {
     get disabled () {
         return "t" in test;
     },
@@ -265,12 +265,12 @@ const template23 = _$createComponent(Component, {
         return "t" in test && "true";
     }
 });
-const template24 = _$createComponent(Component, {
+const template24 = /*#__PURE__*/ _$createComponent(Component, {
     get children () {
         return state.dynamic;
     }
 });
-const template25 = _$createComponent(Component, {
+const template25 = /*#__PURE__*/ _$createComponent(Component, {
     get children () {
         return _tmpl$2();
     }
@@ -84,62 +84,62 @@ const template11 = (()=>{
     })());
     return _el$11;
 })();
-const template12 = _$createComponent(Comp, {
+const template12 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
         return _$memo(()=>!!state.dynamic)() ? good() : bad;
     }
 });
 // no dynamic predicate
-const template13 = _$createComponent(Comp, {
+const template13 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
         return state.dynamic ? good : bad;
     }
 });
-const template14 = _$createComponent(Comp, {
+const template14 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
         return _$memo(()=>!!state.dynamic)() && good();
     }
 });
 // no dynamic predicate
-const template15 = _$createComponent(Comp, {
+const template15 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
         return state.dynamic && good;
     }
 });
-const template16 = _$createComponent(Comp, {
+const template16 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
         return state.dynamic || good();
     }
 });
-const template17 = _$createComponent(Comp, {
+const template17 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
-        return _$memo(()=>!!state.dynamic)() ? _$createComponent(Comp, {}) : _$createComponent(Comp, {});
+        return _$memo(()=>!!state.dynamic)() ? /*#__PURE__*/ _$createComponent(Comp, {}) : /*#__PURE__*/ _$createComponent(Comp, {});
     }
 });
-const template18 = _$createComponent(Comp, {
+const template18 = /*#__PURE__*/ _$createComponent(Comp, {
     get children () {
-        return _$memo(()=>!!state.dynamic)() ? _$createComponent(Comp, {}) : _$createComponent(Comp, {});
+        return _$memo(()=>!!state.dynamic)() ? /*#__PURE__*/ _$createComponent(Comp, {}) : /*#__PURE__*/ _$createComponent(Comp, {});
     }
 });
 const template19 = (()=>{
     const _el$12 = _tmpl$();
-    _$effect(()=>_el$12.innerHTML = state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {}));
+    _$effect(()=>_el$12.innerHTML = state.dynamic ? /*#__PURE__*/ _$createComponent(Comp, {}) : /*#__PURE__*/ _$createComponent(Comp, {}));
     return _el$12;
 })();
 const template20 = (()=>{
     const _el$13 = _tmpl$();
     _$insert(_el$13, (()=>{
         const _c$9 = _$memo(()=>!!state.dynamic);
-        return ()=>_c$9() ? _$createComponent(Comp, {}) : _$createComponent(Comp, {});
+        return ()=>_c$9() ? /*#__PURE__*/ _$createComponent(Comp, {}) : /*#__PURE__*/ _$createComponent(Comp, {});
     })());
     return _el$13;
 })();
-const template21 = _$createComponent(Comp, {
+const template21 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
         return state?.dynamic ? "a" : "b";
     }
 });
-const template22 = _$createComponent(Comp, {
+const template22 = /*#__PURE__*/ _$createComponent(Comp, {
     get children () {
         return state?.dynamic ? "a" : "b";
     }
@@ -154,24 +154,24 @@ const template24 = (()=>{
     _$insert(_el$15, ()=>state?.dynamic ? "a" : "b");
     return _el$15;
 })();
-const template25 = _$createComponent(Comp, {
+const template25 = /*#__PURE__*/ _$createComponent(Comp, {
     get render () {
-        return state.dynamic ?? _$createComponent(Comp, {});
+        return state.dynamic ?? /*#__PURE__*/ _$createComponent(Comp, {});
     }
 });
-const template26 = _$createComponent(Comp, {
+const template26 = /*#__PURE__*/ _$createComponent(Comp, {
     get children () {
-        return state.dynamic ?? _$createComponent(Comp, {});
+        return state.dynamic ?? /*#__PURE__*/ _$createComponent(Comp, {});
     }
 });
 const template27 = (()=>{
     const _el$16 = _tmpl$();
-    _$effect(()=>_el$16.innerHTML = state.dynamic ?? _$createComponent(Comp, {}));
+    _$effect(()=>_el$16.innerHTML = state.dynamic ?? /*#__PURE__*/ _$createComponent(Comp, {}));
     return _el$16;
 })();
 const template28 = (()=>{
     const _el$17 = _tmpl$();
-    _$insert(_el$17, ()=>state.dynamic ?? _$createComponent(Comp, {}));
+    _$insert(_el$17, ()=>state.dynamic ?? /*#__PURE__*/ _$createComponent(Comp, {}));
     return _el$17;
 })();
 const template29 = (()=>{
@@ -187,7 +187,7 @@ const template30 = (()=>{
     _$insert(_el$19, ()=>thing() || thing1() || thing2());
     return _el$19;
 })();
-const template31 = _$createComponent(Comp, {
+const template31 = /*#__PURE__*/ _$createComponent(Comp, {
     get value () {
         return _$memo(()=>!!count())() ? _$memo(()=>!!count())() ? count() : count() : count();
     }
@@ -197,7 +197,7 @@ const template32 = (()=>{
     _$insert(_el$20, ()=>something?.());
     return _el$20;
 })();
-const template33 = _$createComponent(Comp, {
+const template33 = /*#__PURE__*/ _$createComponent(Comp, {
     get children () {
         return something?.();
     }
@@ -39,7 +39,7 @@ const firstDynamic = [
     _tmpl$3()
 ];
 const firstComponent = [
-    _$createComponent(Component, {}),
+    /*#__PURE__*/ _$createComponent(Component, {}),
     _tmpl$3()
 ];
 const lastStatic = [
@@ -52,7 +52,7 @@ const lastDynamic = [
 ];
 const lastComponent = [
     _tmpl$3(),
-    _$createComponent(Component, {})
+    /*#__PURE__*/ _$createComponent(Component, {})
 ];
 const spaces = [
     _tmpl$4(),
@@ -8,7 +8,7 @@ const children = _tmpl$();
 const dynamic = {
     children
 };
-const template = _$createComponent(Module, {
+const template = /*#__PURE__*/ _$createComponent(Module, {
     children: children
 });
 const template2 = (()=>{
@@ -19,7 +19,7 @@ const template2 = (()=>{
 const template3 = _tmpl$3();
 const template4 = (()=>{
     const _el$4 = _tmpl$2();
-    _$insert(_el$4, _$createComponent(Hello, {}));
+    _$insert(_el$4, /*#__PURE__*/ _$createComponent(Hello, {}));
     return _el$4;
 })();
 const template5 = (()=>{
@@ -27,7 +27,7 @@ const template5 = (()=>{
     _$insert(_el$5, ()=>dynamic.children);
     return _el$5;
 })();
-const template6 = _$createComponent(Module, {
+const template6 = /*#__PURE__*/ _$createComponent(Module, {
     get children () {
         return dynamic.children;
     }
@@ -48,7 +48,7 @@ const template9 = (()=>{
     _$insert(_el$8, ()=>dynamic.children);
     return _el$8;
 })();
-const template10 = _$createComponent(Module, _$mergeProps(dynamic, {
+const template10 = /*#__PURE__*/ _$createComponent(Module, _$mergeProps(dynamic, {
     children: "Hello"
 }));
 const template11 = (()=>{
@@ -56,7 +56,7 @@ const template11 = (()=>{
     _$insert(_el$9, state.children);
     return _el$9;
 })();
-const template12 = _$createComponent(Module, {
+const template12 = /*#__PURE__*/ _$createComponent(Module, {
     children: state.children
 });
 const template13 = (()=>{
@@ -64,7 +64,7 @@ const template13 = (()=>{
     _$insert(_el$10, children);
     return _el$10;
 })();
-const template14 = _$createComponent(Module, {
+const template14 = /*#__PURE__*/ _$createComponent(Module, {
     children: children
 });
 const template15 = (()=>{
@@ -72,7 +72,7 @@ const template15 = (()=>{
     _$insert(_el$11, ()=>dynamic.children);
     return _el$11;
 })();
-const template16 = _$createComponent(Module, {
+const template16 = /*#__PURE__*/ _$createComponent(Module, {
     get children () {
         return dynamic.children;
     }
@@ -82,7 +82,7 @@ const template18 = (()=>{
     _$insert(_el$12, children, null);
     return _el$12;
 })();
-const template19 = _$createComponent(Module, {
+const template19 = /*#__PURE__*/ _$createComponent(Module, {
     get children () {
         return [
             "Hi ",
@@ -95,7 +95,7 @@ const template20 = (()=>{
     _$insert(_el$13, children);
     return _el$13;
 })();
-const template21 = _$createComponent(Module, {
+const template21 = /*#__PURE__*/ _$createComponent(Module, {
     get children () {
         return children();
     }
@@ -105,7 +105,7 @@ const template22 = (()=>{
     _$insert(_el$14, ()=>state.children());
     return _el$14;
 })();
-const template23 = _$createComponent(Module, {
+const template23 = /*#__PURE__*/ _$createComponent(Module, {
     get children () {
         return state.children();
     }
@@ -1,7 +1,7 @@
 import { template as _$template } from "r-dom";
 import { insert as _$insert } from "r-dom";
 import { createComponent as _$createComponent } from "r-dom";
-const _tmpl$ = /*#__PURE__*/ _$template(`<span>Hello `), _tmpl$2 = /*#__PURE__*/ _$template(`<span> John`), _tmpl$3 = /*#__PURE__*/ _$template(`<span>Hello John`), _tmpl$4 = /*#__PURE__*/ _$template(`<span> `), _tmpl$5 = /*#__PURE__*/ _$template(`<span> <!> <!> `), _tmpl$6 = /*#__PURE__*/ _$template(`<span> <!> `), _tmpl$7 = /*#__PURE__*/ _$template(`<span>Hello`), _tmpl$8 = /*#__PURE__*/ _$template(`<span>&nbsp;&lt;Hi&gt;&nbsp;`), _tmpl$9 = /*#__PURE__*/ _$template(`<span>Hi&lt;script>alert();&lt;/script>`), _tmpl$10 = /*#__PURE__*/ _$template(`<span>4 + 5 = 9`), _tmpl$11 = /*#__PURE__*/ _$template(`<div>
+const _tmpl$ = /*#__PURE__*/ _$template(`<span>Hello `), _tmpl$2 = /*#__PURE__*/ _$template(`<span> John`), _tmpl$3 = /*#__PURE__*/ _$template(`<span>Hello John`), _tmpl$4 = /*#__PURE__*/ _$template(`<span> `), _tmpl$5 = /*#__PURE__*/ _$template(`<span> <!> <!> `), _tmpl$6 = /*#__PURE__*/ _$template(`<span> <!> `), _tmpl$7 = /*#__PURE__*/ _$template(`<span>Hello`), _tmpl$8 = /*#__PURE__*/ _$template(`<span>&nbsp;&lt;Hi&gt;&nbsp;`), _tmpl$9 = /*#__PURE__*/ _$template(`<span>Hi&lt;script>alert();&lt;/script>`), _tmpl$10 = /*#__PURE__*/ _$template(`<span>4 + 5 = `), _tmpl$11 = /*#__PURE__*/ _$template(`<div>
 d`), _tmpl$12 = /*#__PURE__*/ _$template(`<div>`), _tmpl$13 = /*#__PURE__*/ _$template(`<div normal="Search…" title="Search&amp;hellip;">`), _tmpl$14 = /*#__PURE__*/ _$template(`<div><div>`);
 const trailing = _tmpl$();
 const leading = _tmpl$2();
@@ -38,7 +38,7 @@ const leadingExpr = (()=>{
 /* prettier-ignore */ const multiLineTrailingSpace = _tmpl$3();
 /* prettier-ignore */ const multiLineNoTrailingSpace = _tmpl$3();
 /* prettier-ignore */ const escape = _tmpl$8();
-/* prettier-ignore */ const escape2 = _$createComponent(Comp, {
+/* prettier-ignore */ const escape2 = /*#__PURE__*/ _$createComponent(Comp, {
     children: "\xa0<Hi>\xa0"
 });
 /* prettier-ignore */ const escape3 = "\xa0<Hi>\xa0";
@@ -50,27 +50,31 @@ const evaluated = (()=>{
     return _el$25;
 })();
 let number = 4 + 5;
-const evaluatedNonString = _tmpl$10();
+const evaluatedNonString = (()=>{
+    const _el$27 = _tmpl$10(), _el$28 = _el$27.firstChild;
+    _$insert(_el$27, number, null);
+    return _el$27;
+})();
 const newLineLiteral = (()=>{
-    const _el$28 = _tmpl$11(), _el$29 = _el$28.firstChild;
-    _$insert(_el$28, s, _el$29);
-    return _el$28;
+    const _el$29 = _tmpl$11(), _el$30 = _el$29.firstChild;
+    _$insert(_el$29, s, _el$30);
+    return _el$29;
 })();
 const trailingSpace = (()=>{
-    const _el$30 = _tmpl$12();
-    _$insert(_el$30, expr);
-    return _el$30;
+    const _el$31 = _tmpl$12();
+    _$insert(_el$31, expr);
+    return _el$31;
 })();
-const trailingSpaceComp = _$createComponent(Comp, {
+const trailingSpaceComp = /*#__PURE__*/ _$createComponent(Comp, {
     children: expr
 });
 const trailingSpaceFrag = expr;
 const leadingSpaceElement = (()=>{
-    const _el$31 = _tmpl$4(), _el$32 = _el$31.firstChild;
-    _$insert(_el$31, expr, null);
-    return _el$31;
+    const _el$32 = _tmpl$4(), _el$33 = _el$32.firstChild;
+    _$insert(_el$32, expr, null);
+    return _el$32;
 })();
-const leadingSpaceComponent = _$createComponent(Div, {
+const leadingSpaceComponent = /*#__PURE__*/ _$createComponent(Div, {
     get children () {
         return [
             " ",
@@ -83,11 +87,11 @@ const leadingSpaceFragment = [
     expr
 ];
 const trailingSpaceElement = (()=>{
-    const _el$33 = _tmpl$4(), _el$34 = _el$33.firstChild;
-    _$insert(_el$33, expr, _el$34);
-    return _el$33;
+    const _el$34 = _tmpl$4(), _el$35 = _el$34.firstChild;
+    _$insert(_el$34, expr, _el$35);
+    return _el$34;
 })();
-const trailingSpaceComponent = _$createComponent(Div, {
+const trailingSpaceComponent = /*#__PURE__*/ _$createComponent(Div, {
     get children () {
         return [
             expr,
@@ -100,12 +104,12 @@ const trailingSpaceFragment = [
     " "
 ];
 const escapeAttribute = _tmpl$13();
-const escapeCompAttribute = _$createComponent(Div, {
+const escapeCompAttribute = /*#__PURE__*/ _$createComponent(Div, {
     normal: "Search…",
     title: "Search&hellip;"
 });
 const lastElementExpression = (()=>{
-    const _el$36 = _tmpl$14(), _el$37 = _el$36.firstChild;
-    _$insert(_el$36, expr, null);
-    return _el$36;
+    const _el$37 = _tmpl$14(), _el$38 = _el$37.firstChild;
+    _$insert(_el$37, expr, null);
+    return _el$37;
 })();
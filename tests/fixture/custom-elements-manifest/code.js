@@ -0,0 +1,19 @@
+const template = (
+  <ds-select
+    items={items}
+    selected={state.selected}
+    label={label}
+    size="large"
+    open
+    onChange={select}
+    onValueChanged={changed}
+    onClick={clicked}
+    id="picker"
+    aria-label="Pick one"
+    unknown={thing}
+  />
+);
+
+const template2 = <ds-select attr:label={label} prop:size={size} />;
+
+const template3 = <other-element items={items} />;
@@ -0,0 +1,35 @@
+import { template as _$template } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { getOwner as _$getOwner } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<ds-select size="large" open id="picker" aria-label="Pick one">`, true, false), _tmpl$2 = /*#__PURE__*/ _$template(`<ds-select>`, true, false), _tmpl$3 = /*#__PURE__*/ _$template(`<other-element>`, true, false);
+const template = (()=>{
+    const _el$ = _tmpl$();
+    _$addEventListener(_el$, "click", clicked, true);
+    _el$.items = items;
+    _$setAttribute(_el$, "label", label);
+    _el$.addEventListener("change", select);
+    _el$.addEventListener("value-changed", changed);
+    _el$.unknown = thing;
+    _el$._$owner = _$getOwner();
+    _$effect(()=>_el$.selected = state.selected);
+    return _el$;
+})();
+const template2 = (()=>{
+    const _el$2 = _tmpl$2();
+    _$setAttribute(_el$2, "label", label);
+    _el$2.size = size;
+    _el$2._$owner = _$getOwner();
+    return _el$2;
+})();
+const template3 = (()=>{
+    const _el$3 = _tmpl$3();
+    _el$3.items = items;
+    _el$3._$owner = _$getOwner();
+    return _el$3;
+})();
+_$delegateEvents([
+    "click"
+]);
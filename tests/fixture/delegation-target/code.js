@@ -0,0 +1,5 @@
+const template = (
+  <div onClick={() => console.log("clicked")}>
+    <input onInput={e => console.log(e.target.value)} />
+  </div>
+);
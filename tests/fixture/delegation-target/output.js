@@ -0,0 +1,13 @@
+import { template as _$template } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div><input>`);
+const template = /*#__PURE__*/ (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild;
+    _el$.$$click = ()=>console.log("clicked");
+    _el$2.$$input = (e)=>console.log(e.target.value);
+    return _el$;
+})();
+_$delegateEvents([
+    "click",
+    "input"
+], window.appRoot);
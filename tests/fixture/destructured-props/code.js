@@ -0,0 +1,48 @@
+function Card({ title, children }) {
+  return (
+    <div class="card">
+      <h2>{title}</h2>
+      {children}
+    </div>
+  );
+}
+
+const Button = ({ label = "Click", size: buttonSize = "md", onClick, ...rest }) => (
+  <button class={buttonSize} onClick={onClick} {...rest}>
+    {label}
+  </button>
+);
+
+const Badge = ({ count, "aria-label": ariaLabel }) => {
+  const info = { count, ariaLabel };
+  return <span aria-label={ariaLabel}>{info.count}</span>;
+};
+
+// nested destructuring can't be rewritten
+function Nested({ user: { name } }) {
+  return <p>{name}</p>;
+}
+
+// reassigned bindings can't be rewritten
+function Reassigned({ value }) {
+  value = value || "none";
+  return <p>{value}</p>;
+}
+
+// not a component
+function helper({ a, b }) {
+  return a + b;
+}
+
+const Heading = ({ as: Tag = "h2", icon: Icon, label }) => (
+  <Tag class="heading">
+    <Icon.Small />
+    {label}
+  </Tag>
+);
+
+// `<props["my-tag"]>` can't be written in JSX
+const Custom = ({ "my-tag": MyTag }) => <MyTag />;
+
+// the parameter default stays on `props`
+const Optional = ({ label } = {}) => <span>{label}</span>;
@@ -0,0 +1,98 @@
+import { template as _$template } from "solid-js/web";
+import { spread as _$spread } from "solid-js/web";
+import { setAttribute as _$setAttribute } from "solid-js/web";
+import { mergeProps as _$mergeProps } from "solid-js/web";
+import { memo as _$memo } from "solid-js/web";
+import { insert as _$insert } from "solid-js/web";
+import { effect as _$effect } from "solid-js/web";
+import { createComponent as _$createComponent } from "solid-js/web";
+import { splitProps as _$splitProps } from "solid-js";
+import { mergeProps as _$mergeProps1 } from "solid-js";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="card"><h2>`), _tmpl$2 = /*#__PURE__*/ _$template(`<button>`), _tmpl$3 = /*#__PURE__*/ _$template(`<span>`), _tmpl$4 = /*#__PURE__*/ _$template(`<p>`);
+function Card(props) {
+    return (()=>{
+        const _el$ = _tmpl$(), _el$2 = _el$.firstChild;
+        _$insert(_el$2, ()=>props.title);
+        _$insert(_el$, ()=>props.children, null);
+        return _el$;
+    })();
+}
+const Button = (props)=>{
+    props = _$mergeProps1({
+        label: "Click",
+        size: "md"
+    }, props);
+    const [, rest] = _$splitProps(props, [
+        "label",
+        "size",
+        "onClick"
+    ]);
+    return (()=>{
+        const _el$3 = _tmpl$2();
+        _$spread(_el$3, _$mergeProps({
+            get "class" () {
+                return props.size;
+            },
+            get onClick () {
+                return props.onClick;
+            }
+        }, rest), false, true);
+        _$insert(_el$3, ()=>props.label);
+        return _el$3;
+    })();
+};
+const Badge = (props)=>{
+    const info = {
+        count: props.count,
+        ariaLabel: props["aria-label"]
+    };
+    return (()=>{
+        const _el$4 = _tmpl$3();
+        _$insert(_el$4, ()=>info.count);
+        _$effect(()=>_$setAttribute(_el$4, "aria-label", props["aria-label"]));
+        return _el$4;
+    })();
+};
+// nested destructuring can't be rewritten
+function Nested({ user: { name } }) {
+    return (()=>{
+        const _el$5 = _tmpl$4();
+        _$insert(_el$5, name);
+        return _el$5;
+    })();
+}
+// reassigned bindings can't be rewritten
+function Reassigned({ value }) {
+    value = value || "none";
+    return (()=>{
+        const _el$6 = _tmpl$4();
+        _$insert(_el$6, value);
+        return _el$6;
+    })();
+}
+// not a component
+function helper({ a, b }) {
+    return a + b;
+}
+const Heading = (props)=>{
+    props = _$mergeProps1({
+        as: "h2"
+    }, props);
+    return /*#__PURE__*/ _$createComponent(props.as, {
+        "class": "heading",
+        get children () {
+            return [
+                /*#__PURE__*/ _$createComponent(props.icon.Small, {}),
+                _$memo(()=>props.label)
+            ];
+        }
+    });
+};
+// `<props["my-tag"]>` can't be written in JSX
+const Custom = ({ "my-tag": MyTag })=>/*#__PURE__*/ _$createComponent(MyTag, {});
+// the parameter default stays on `props`
+const Optional = (props = {})=>(()=>{
+        const _el$7 = _tmpl$3();
+        _$insert(_el$7, ()=>props.label);
+        return _el$7;
+    })();
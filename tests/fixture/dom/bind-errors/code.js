@@ -0,0 +1,11 @@
+const handled = <input bind:value={[name, setName]} onInput={e => track(e)} />;
+
+const delegated = <input type="checkbox" bind:checked={agreed} on:change={save} />;
+
+const unsupported = <input bind:files={files} />;
+
+const call = <input bind:value={createSignal("")} />;
+
+const literal = <input bind:value="name" />;
+
+const other = <input bind:value={[name, setName]} onChange={save} />;
@@ -0,0 +1,28 @@
+import { template as _$template } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<input>`), _tmpl$2 = /*#__PURE__*/ _$template(`<input type="checkbox">`);
+const handled = /*#__PURE__*/ (()=>{
+    const _el$ = _tmpl$();
+    _el$.$$input = (e)=>track(e);
+    return _el$;
+})();
+const delegated = (()=>{
+    const _el$2 = _tmpl$2();
+    _el$2.addEventListener("change", save);
+    return _el$2;
+})();
+const unsupported = _tmpl$();
+const call = _tmpl$();
+const literal = _tmpl$();
+const other = (()=>{
+    const _el$6 = _tmpl$();
+    _$addEventListener(_el$6, "change", save);
+    _el$6.$$input = (e)=>setName(e.currentTarget.value);
+    _$effect(()=>_el$6.value = name());
+    return _el$6;
+})();
+_$delegateEvents([
+    "input"
+]);
@@ -0,0 +1,19 @@
+const [name, setName] = createSignal("");
+const agreed = createSignal(false);
+
+const text = <input bind:value={[name, setName]} />;
+
+const number = <input type="number" bind:value={[age, setAge]} />;
+
+const checkbox = <input type="checkbox" bind:checked={agreed} />;
+
+const select = (
+  <select bind:value={[choice, v => setChoice(v)]}>
+    <option value="a">A</option>
+    <option value="b">B</option>
+  </select>
+);
+
+const textarea = <textarea bind:value={[() => state.notes, notes => setState({ notes })]} />;
+
+const member = <input type="checkbox" bind:checked={signals.agreed} />;
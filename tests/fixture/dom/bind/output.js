@@ -0,0 +1,47 @@
+import { template as _$template } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<input>`), _tmpl$2 = /*#__PURE__*/ _$template(`<input type="number">`), _tmpl$3 = /*#__PURE__*/ _$template(`<input type="checkbox">`), _tmpl$4 = /*#__PURE__*/ _$template(`<select><option value="a">A</option><option value="b">B`), _tmpl$5 = /*#__PURE__*/ _$template(`<textarea>`);
+const [name, setName] = createSignal("");
+const agreed = createSignal(false);
+const text = (()=>{
+    const _el$ = _tmpl$();
+    _el$.$$input = (e)=>setName(e.currentTarget.value);
+    _$effect(()=>_el$.value = name());
+    return _el$;
+})();
+const number = (()=>{
+    const _el$2 = _tmpl$2();
+    _el$2.$$input = (e)=>setAge(e.currentTarget.valueAsNumber);
+    _$effect(()=>_el$2.valueAsNumber = age());
+    return _el$2;
+})();
+const checkbox = (()=>{
+    const _el$3 = _tmpl$3();
+    _el$3.addEventListener("change", (e)=>agreed[1](e.currentTarget.checked));
+    _$effect(()=>_el$3.checked = agreed[0]());
+    return _el$3;
+})();
+const select = (()=>{
+    const _el$4 = _tmpl$4();
+    _el$4.addEventListener("change", (e)=>((v)=>setChoice(v))(e.currentTarget.value));
+    _$effect(()=>_el$4.value = choice());
+    return _el$4;
+})();
+const textarea = (()=>{
+    const _el$5 = _tmpl$5();
+    _el$5.$$input = (e)=>((notes)=>setState({
+                notes
+            }))(e.currentTarget.value);
+    _$effect(()=>_el$5.value = (()=>state.notes)());
+    return _el$5;
+})();
+const member = (()=>{
+    const _el$6 = _tmpl$3();
+    _el$6.addEventListener("change", (e)=>signals.agreed[1](e.currentTarget.checked));
+    _$effect(()=>_el$6.checked = signals.agreed[0]());
+    return _el$6;
+})();
+_$delegateEvents([
+    "input"
+]);
@@ -0,0 +1,16 @@
+import { Show as ImportedShow } from "./components";
+
+const unbound = (
+  <For each={list}>{item => <Show when={item.visible}>{item.name}</Show>}</For>
+);
+
+const imported = <ImportedShow when={visible}>Hi</ImportedShow>;
+
+function Shadowed() {
+  const For = props => props.children;
+  return <For each={list}>Hi</For>;
+}
+
+function Param(Show) {
+  return <Show when={visible}>Hi</Show>;
+}
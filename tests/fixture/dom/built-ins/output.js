@@ -0,0 +1,32 @@
+import { createComponent as _$createComponent } from "r-dom";
+import { Show as _$Show } from "r-dom";
+import { For as _$For } from "r-dom";
+import { Show as ImportedShow } from "./components";
+const unbound = /*#__PURE__*/ _$createComponent(_$For, {
+    each: list,
+    children: (item)=>/*#__PURE__*/ _$createComponent(_$Show, {
+            get when () {
+                return item.visible;
+            },
+            get children () {
+                return item.name;
+            }
+        })
+});
+const imported = /*#__PURE__*/ _$createComponent(ImportedShow, {
+    when: visible,
+    children: "Hi"
+});
+function Shadowed() {
+    const For = (props)=>props.children;
+    return /*#__PURE__*/ _$createComponent(For, {
+        each: list,
+        children: "Hi"
+    });
+}
+function Param(Show) {
+    return /*#__PURE__*/ _$createComponent(Show, {
+        when: visible,
+        children: "Hi"
+    });
+}
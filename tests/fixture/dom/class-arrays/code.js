@@ -0,0 +1,15 @@
+const template = <div class={["base", active() && "active", { disabled: d(), hidden: false, shown: true }]} />;
+
+const template2 = <div class={["btn", size === "large" ? "btn-lg" : "btn-sm", isOn && "on"]} />;
+
+const template3 = <div class={[base, "static", props.class]} />;
+
+const template4 = <div class={{ "a b": toggled(), c: true }} />;
+
+const template5 = <div class="first" class={[false, null, "", 0, "second", ["nested", cond && "deep"]]} />;
+
+const template6 = <div class={[...list]} />;
+
+const template7 = <div class={[props.class, active() && "active"]} />;
+
+const template8 = <div class={["card", { [kind()]: true, selected: selected() }]} />;
@@ -0,0 +1,87 @@
+import { template as _$template } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { classList as _$classList } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="base shown">`), _tmpl$2 = /*#__PURE__*/ _$template(`<div class="btn">`), _tmpl$3 = /*#__PURE__*/ _$template(`<div class="static">`), _tmpl$4 = /*#__PURE__*/ _$template(`<div class="c">`), _tmpl$5 = /*#__PURE__*/ _$template(`<div class="first second nested">`), _tmpl$6 = /*#__PURE__*/ _$template(`<div>`), _tmpl$7 = /*#__PURE__*/ _$template(`<div class="card">`);
+const template = (()=>{
+    const _el$ = _tmpl$();
+    _$effect((_p$)=>{
+        const _v$ = !!active(), _v$2 = !!d();
+        _v$ !== _p$._v$ && _el$.classList.toggle("active", _p$._v$ = _v$);
+        _v$2 !== _p$._v$2 && _el$.classList.toggle("disabled", _p$._v$2 = _v$2);
+        return _p$;
+    }, {
+        _v$: undefined,
+        _v$2: undefined
+    });
+    return _el$;
+})();
+const template2 = (()=>{
+    const _el$2 = _tmpl$2();
+    _el$2.classList.toggle("btn-lg", size === "large");
+    _el$2.classList.toggle("btn-sm", size !== "large");
+    _el$2.classList.toggle("on", !!isOn);
+    return _el$2;
+})();
+const template3 = (()=>{
+    const _el$3 = _tmpl$3();
+    _$effect((_$p)=>_$classList(_el$3, {
+            [base || ""]: true,
+            [props.class || ""]: true
+        }, _$p));
+    return _el$3;
+})();
+const template4 = (()=>{
+    const _el$4 = _tmpl$4();
+    _$effect((_p$)=>{
+        const _v$3 = !!toggled(), _v$4 = !!toggled();
+        _v$3 !== _p$._v$3 && _el$4.classList.toggle("a", _p$._v$3 = _v$3);
+        _v$4 !== _p$._v$4 && _el$4.classList.toggle("b", _p$._v$4 = _v$4);
+        return _p$;
+    }, {
+        _v$3: undefined,
+        _v$4: undefined
+    });
+    return _el$4;
+})();
+const template5 = (()=>{
+    const _el$5 = _tmpl$5();
+    _el$5.classList.toggle("deep", !!cond);
+    return _el$5;
+})();
+const template6 = (()=>{
+    const _el$6 = _tmpl$6();
+    _$effect((_$p)=>_$classList(_el$6, {
+            [list.filter(Boolean).join(" ") || ""]: true
+        }, _$p));
+    return _el$6;
+})();
+const template7 = (()=>{
+    const _el$7 = _tmpl$6();
+    _$effect((_p$)=>{
+        const _v$5 = {
+            [props.class || ""]: true
+        }, _v$6 = !!active();
+        _p$._v$5 = _$classList(_el$7, _v$5, _p$._v$5);
+        _v$6 !== _p$._v$6 && _el$7.classList.toggle("active", _p$._v$6 = _v$6);
+        return _p$;
+    }, {
+        _v$5: undefined,
+        _v$6: undefined
+    });
+    return _el$7;
+})();
+const template8 = (()=>{
+    const _el$8 = _tmpl$7();
+    _$effect((_p$)=>{
+        const _v$7 = {
+            [kind()]: true
+        }, _v$8 = !!selected();
+        _p$._v$7 = _$classList(_el$8, _v$7, _p$._v$7);
+        _v$8 !== _p$._v$8 && _el$8.classList.toggle("selected", _p$._v$8 = _v$8);
+        return _p$;
+    }, {
+        _v$7: undefined,
+        _v$8: undefined
+    });
+    return _el$8;
+})();
@@ -0,0 +1,3 @@
+const a = <div class="base" classList={{ hidden: false, shown: true, on: 1, off: 0, empty: "", dyn: state.on }} />;
+const b = <div style="display:block" style={{ color: "red", "font-size": 12, margin: null, padding: false, width: state.w }} />;
+const c = <div class="x" classList={{ a: true }} style={{ color: "red" }} />;
@@ -0,0 +1,14 @@
+import { template as _$template } from "r-dom";
+import { effect as _$effect } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="base shown on">`), _tmpl$2 = /*#__PURE__*/ _$template(`<div style="display:block;color:red;font-size:12">`), _tmpl$3 = /*#__PURE__*/ _$template(`<div class="x a" style="color:red">`);
+const a = (()=>{
+    const _el$ = _tmpl$();
+    _$effect(()=>_el$.classList.toggle("dyn", !!state.on));
+    return _el$;
+})();
+const b = (()=>{
+    const _el$2 = _tmpl$2();
+    _$effect(()=>state.w != null ? _el$2.style.setProperty("width", state.w) : _el$2.style.removeProperty("width"));
+    return _el$2;
+})();
+const c = _tmpl$3();
@@ -0,0 +1,11 @@
+/**
+ * @jsxDomExpressions delegateEvents=false moduleName=custom-dom
+ * @jsxDomExpressions builtIns=["Portal"] unknownOption=1
+ */
+/* @jsxDomExpressions hydratable=true generate=universal */
+const template = (
+  <div onClick={() => console.log("clicked")}>
+    <Portal>{props.children}</Portal>
+    <For each={list}>{item => item}</For>
+  </div>
+);
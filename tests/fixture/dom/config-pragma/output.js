@@ -0,0 +1,22 @@
+/**
+ * @jsxDomExpressions delegateEvents=false moduleName=custom-dom
+ * @jsxDomExpressions builtIns=["Portal"] unknownOption=1
+ */ /* @jsxDomExpressions hydratable=true generate=universal */ import { template as _$template } from "custom-dom";
+import { insert as _$insert } from "custom-dom";
+import { createComponent as _$createComponent } from "custom-dom";
+import { Portal as _$Portal } from "custom-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div>`);
+const template = (()=>{
+    const _el$ = _tmpl$();
+    _el$.addEventListener("click", ()=>console.log("clicked"));
+    _$insert(_el$, /*#__PURE__*/ _$createComponent(_$Portal, {
+        get children () {
+            return props.children;
+        }
+    }), null);
+    _$insert(_el$, /*#__PURE__*/ _$createComponent(For, {
+        each: list,
+        children: (item)=>item
+    }), null);
+    return _el$;
+})();
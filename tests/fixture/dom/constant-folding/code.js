@@ -0,0 +1,14 @@
+const big = 1e21;
+const negativeZero = -0;
+const tiny = 1 / 1e7;
+const label = "Total: " + big;
+let count = 1;
+for (count in items) {}
+
+const folded = <span title={label}>{big} {negativeZero} {tiny}</span>;
+const notFolded = <span title={count}>{count}</span>;
+
+const wrapped = ~3000000000;
+const hex = +"0x10";
+const notANumber = +"inf";
+const numbers = <span data-wrapped={wrapped} data-hex={hex} data-nan={notANumber} />;
@@ -0,0 +1,21 @@
+import { template as _$template } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { insert as _$insert } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<span title="Total: 1e+21">1e+21 0 1e-7`), _tmpl$2 = /*#__PURE__*/ _$template(`<span>`), _tmpl$3 = /*#__PURE__*/ _$template(`<span data-wrapped="1294967295" data-hex="16" data-nan="NaN">`);
+const big = 1e21;
+const negativeZero = -0;
+const tiny = 1 / 1e7;
+const label = "Total: " + big;
+let count = 1;
+for(count in items){}
+const folded = _tmpl$();
+const notFolded = (()=>{
+    const _el$2 = _tmpl$2();
+    _$setAttribute(_el$2, "title", count);
+    _$insert(_el$2, count);
+    return _el$2;
+})();
+const wrapped = ~3000000000;
+const hex = +"0x10";
+const notANumber = +"inf";
+const numbers = _tmpl$3();
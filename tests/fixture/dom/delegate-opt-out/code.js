@@ -0,0 +1,9 @@
+const template = (
+  <div onClick={handleClick}>
+    <button onClick={() => console.log("clicked")} delegate:false>
+      Inside shadow root
+    </button>
+    <input onInput={[handleInput, 1]} onKeyDown={handleKeyDown} delegate:false />
+    <span onClick={handleClick} delegate:true />
+  </div>
+);
@@ -0,0 +1,16 @@
+import { template as _$template } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div><button>Inside shadow root</button><input><span>`);
+const template = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling, _el$4 = _el$3.nextSibling;
+    _$addEventListener(_el$, "click", handleClick, true);
+    _el$2.addEventListener("click", ()=>console.log("clicked"));
+    _$addEventListener(_el$3, "keydown", handleKeyDown);
+    _el$3.addEventListener("input", (e)=>handleInput(1, e));
+    _$addEventListener(_el$4, "click", handleClick, true);
+    return _el$;
+})();
+_$delegateEvents([
+    "click"
+]);
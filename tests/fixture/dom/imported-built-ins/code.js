@@ -0,0 +1,3 @@
+import { For } from "./my-for";
+
+const imported = <For each={list}>Hi</For>;
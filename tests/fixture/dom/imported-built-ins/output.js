@@ -0,0 +1,6 @@
+import { createComponent as _$createComponent } from "r-dom";
+import { For } from "./my-for";
+const imported = /*#__PURE__*/ _$createComponent(For, {
+    each: list,
+    children: "Hi"
+});
@@ -0,0 +1,31 @@
+class List extends Base {
+  header = <Title text={this.title} />;
+
+  constructor() {
+    super();
+    this.view = <Item value={this.value} />;
+  }
+
+  render = () => <Item value={this.value} onSelect={() => this.select()} />;
+
+  get footer() {
+    return <Item value={this.count} />;
+  }
+
+  static {
+    this.template = <Item value={this.name} />;
+  }
+}
+
+function Wrapper() {
+  return (
+    <Item
+      first={arguments[0]}
+      target={new.target}
+      handler={function () {
+        return this.own;
+      }}
+      callback={compose(() => this.outer)}
+    />
+  );
+}
@@ -0,0 +1,60 @@
+import { createComponent as _$createComponent } from "r-dom";
+class List extends Base {
+    header = (()=>{
+        const _self$ = this;
+        return /*#__PURE__*/ _$createComponent(Title, {
+            get text () {
+                return _self$.title;
+            }
+        });
+    })();
+    constructor(){
+        super();
+        const _self$ = this;
+        this.view = /*#__PURE__*/ _$createComponent(Item, {
+            get value () {
+                return _self$.value;
+            }
+        });
+    }
+    render = ()=>{
+        const _self$ = this;
+        return /*#__PURE__*/ _$createComponent(Item, {
+            get value () {
+                return _self$.value;
+            },
+            onSelect: ()=>_self$.select()
+        });
+    };
+    get footer() {
+        const _self$ = this;
+        return /*#__PURE__*/ _$createComponent(Item, {
+            get value () {
+                return _self$.count;
+            }
+        });
+    }
+    static{
+        const _self$ = this;
+        this.template = /*#__PURE__*/ _$createComponent(Item, {
+            get value () {
+                return _self$.name;
+            }
+        });
+    }
+}
+function Wrapper() {
+    const _self$ = this, _arguments$ = arguments, _newTarget$ = new.target;
+    return /*#__PURE__*/ _$createComponent(Item, {
+        get first () {
+            return _arguments$[0];
+        },
+        target: _newTarget$,
+        handler: function() {
+            return this.own;
+        },
+        get callback () {
+            return compose(()=>_self$.outer);
+        }
+    });
+}
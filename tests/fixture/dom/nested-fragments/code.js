@@ -0,0 +1,37 @@
+const list = (
+  <ul>
+    <>
+      {a}
+      <li />
+    </>
+  </ul>
+);
+
+const staticList = (
+  <ul>
+    <li>First</li>
+    <>
+      <li>Second</li>
+      <li>Third</li>
+    </>
+  </ul>
+);
+
+const deepList = (
+  <ul>
+    <>
+      <>
+        <li>{state.first}</li>
+      </>
+      {state.rest}
+    </>
+    <li>Last</li>
+  </ul>
+);
+
+const conditional = (
+  <div>
+    <>{show() ? <span>yes</span> : <span>no</span>}</>
+    <footer />
+  </div>
+);
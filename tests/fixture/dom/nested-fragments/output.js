@@ -0,0 +1,24 @@
+import { template as _$template } from "r-dom";
+import { memo as _$memo } from "r-dom";
+import { insert as _$insert } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<ul><li>`), _tmpl$2 = /*#__PURE__*/ _$template(`<ul><li>First</li><li>Second</li><li>Third`), _tmpl$3 = /*#__PURE__*/ _$template(`<ul><li></li><li>Last`), _tmpl$4 = /*#__PURE__*/ _$template(`<div><footer>`), _tmpl$5 = /*#__PURE__*/ _$template(`<span>yes`), _tmpl$6 = /*#__PURE__*/ _$template(`<span>no`);
+const list = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild;
+    _$insert(_el$, a, _el$2);
+    return _el$;
+})();
+const staticList = _tmpl$2();
+const deepList = (()=>{
+    const _el$4 = _tmpl$3(), _el$5 = _el$4.firstChild, _el$6 = _el$5.nextSibling;
+    _$insert(_el$5, ()=>state.first);
+    _$insert(_el$4, ()=>state.rest, _el$6);
+    return _el$4;
+})();
+const conditional = (()=>{
+    const _el$7 = _tmpl$4(), _el$8 = _el$7.firstChild;
+    _$insert(_el$7, (()=>{
+        const _c$ = _$memo(()=>!!show());
+        return ()=>_c$() ? _tmpl$5() : _tmpl$6();
+    })(), _el$8);
+    return _el$7;
+})();
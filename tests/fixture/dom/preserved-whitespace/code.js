@@ -0,0 +1,41 @@
+const code = (
+  <pre>
+    function hello() {"{"}
+      return {value};
+    {"}"}
+  </pre>
+);
+
+const nested = (
+  <pre>
+    <code>
+      const a = 1;
+        const b = 2;
+    </code>
+  </pre>
+);
+
+const textarea = (
+  <textarea>
+    Line one
+      Line two
+  </textarea>
+);
+
+const outside = (
+  <div>
+    collapsed
+      text
+  </div>
+);
+
+const paragraphs = (
+  <pre>
+    first
+
+    second
+
+
+    third &amp; last
+  </pre>
+);
@@ -0,0 +1,31 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<pre>
+    function hello() {
+      return <!>;
+    }
+  `), _tmpl$2 = /*#__PURE__*/ _$template(`<pre>
+    <code>
+      const a = 1;
+        const b = 2;
+    </code>
+  `), _tmpl$3 = /*#__PURE__*/ _$template(`<textarea>
+    Line one
+      Line two
+  `), _tmpl$4 = /*#__PURE__*/ _$template(`<div>collapsed text`), _tmpl$5 = /*#__PURE__*/ _$template(`<pre>
+    first
+
+    second
+
+
+    third &amp; last
+  `);
+const code = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$6 = _el$2.nextSibling, _el$5 = _el$6.nextSibling;
+    _$insert(_el$, value, _el$6);
+    return _el$;
+})();
+const nested = _tmpl$2();
+const textarea = _tmpl$3();
+const outside = _tmpl$4();
+const paragraphs = _tmpl$5();
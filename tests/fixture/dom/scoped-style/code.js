@@ -0,0 +1,43 @@
+const Card = (props) => (
+  <div class="card">
+    <style scoped>{`
+      /* the card itself */
+      .card { padding: 8px; border: 1px solid #ccc; }
+      .card > h2, .card .body p:first-child::before {
+        content: "\\201C";
+      }
+      a:hover { color: red; }
+      @media (max-width: 600px) {
+        .card { padding: 4px; }
+      }
+      @keyframes fade { from { opacity: 0; } to { opacity: 1; } }
+    `}</style>
+    <h2>{props.title}</h2>
+    <div class="body">
+      <p>{props.children}</p>
+      <a href="#">More</a>
+    </div>
+  </div>
+);
+
+const List = () => (
+  <>
+    <ul>
+      <li>One</li>
+    </ul>
+    <svg>
+      <circle r="5" />
+    </svg>
+  </>
+);
+
+const Spinner = () => (
+  <span class="spinner">
+    <style scoped>{`
+      .spinner { animation: spin 1s linear infinite, fade 2s; }
+      .spinner:hover { animation-name: spin; }
+      @-webkit-keyframes spin { to { transform: rotate(360deg); } }
+      @keyframes spin { to { transform: rotate(360deg); } }
+    `}</style>
+  </span>
+);
@@ -0,0 +1,21 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div data-s-5a6668a1 class="card"><h2 data-s-5a6668a1></h2><div data-s-5a6668a1 class="body"><p data-s-5a6668a1></p><a data-s-5a6668a1 href="#">More`), _tmpl$2 = /*#__PURE__*/ _$template(`<ul data-s-5a6668a1><li data-s-5a6668a1>One`), _tmpl$3 = /*#__PURE__*/ _$template(`<svg data-s-5a6668a1><circle data-s-5a6668a1 r="5">`), _tmpl$4 = /*#__PURE__*/ _$template(`<span data-s-5a6668a1 class="spinner">`);
+(()=>{
+    if (typeof document === "undefined" || document.head.querySelector("style[data-s-5a6668a1]")) return;
+    const style = document.createElement("style");
+    style.setAttribute("data-s-5a6668a1", "");
+    style.textContent = '.card[data-s-5a6668a1]{padding: 8px; border: 1px solid #ccc;}.card > h2[data-s-5a6668a1],.card .body p[data-s-5a6668a1]:first-child::before{content: "\\201C";}a[data-s-5a6668a1]:hover{color: red;}@media (max-width: 600px){.card[data-s-5a6668a1]{padding: 4px;}}@keyframes fade-5a6668a1{from { opacity: 0; } to { opacity: 1; }}.spinner[data-s-5a6668a1]{animation: spin-5a6668a1 1s linear infinite, fade-5a6668a1 2s;}.spinner[data-s-5a6668a1]:hover{animation-name: spin-5a6668a1;}@-webkit-keyframes spin-5a6668a1{to { transform: rotate(360deg); }}@keyframes spin-5a6668a1{to { transform: rotate(360deg); }}';
+    document.head.appendChild(style);
+})();
+const Card = (props)=>(()=>{
+        const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling, _el$4 = _el$3.firstChild;
+        _$insert(_el$2, ()=>props.title);
+        _$insert(_el$4, ()=>props.children);
+        return _el$;
+    })();
+const List = ()=>[
+        _tmpl$2(),
+        _tmpl$3()
+    ];
+const Spinner = ()=>_tmpl$4();
@@ -0,0 +1,28 @@
+const dynamicValue = (
+  <select value={selected()}>
+    <For each={options()}>{option => <option value={option.id}>{option.label}</option>}</For>
+  </select>
+);
+
+const staticValue = (
+  <select value={initial}>
+    {options.map(option => (
+      <option value={option}>{option}</option>
+    ))}
+  </select>
+);
+
+const staticOptions = (
+  <select value={selected()}>
+    <option value="a">A</option>
+    <option value="b">B</option>
+  </select>
+);
+
+const bound = (
+  <select bind:value={[choice, setChoice]}>
+    <optgroup label="Group">
+      <For each={choices()}>{choice => <option>{choice}</option>}</For>
+    </optgroup>
+  </select>
+);
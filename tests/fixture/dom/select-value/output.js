@@ -0,0 +1,54 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { For as _$For } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<select>`), _tmpl$2 = /*#__PURE__*/ _$template(`<option>`), _tmpl$3 = /*#__PURE__*/ _$template(`<select><option value="a">A</option><option value="b">B`), _tmpl$4 = /*#__PURE__*/ _$template(`<select><optgroup label="Group">`);
+const dynamicValue = (()=>{
+    const _el$ = _tmpl$();
+    _$insert(_el$, /*#__PURE__*/ _$createComponent(_$For, {
+        get each () {
+            return options();
+        },
+        children: (option)=>(()=>{
+                const _el$2 = _tmpl$2();
+                _$insert(_el$2, ()=>option.label);
+                _$effect(()=>_el$2.value = option.id);
+                return _el$2;
+            })()
+    }));
+    _$effect(()=>_el$.value = selected());
+    return _el$;
+})();
+const staticValue = (()=>{
+    const _el$3 = _tmpl$();
+    _$insert(_el$3, ()=>options.map((option)=>(()=>{
+                const _el$4 = _tmpl$2();
+                _el$4.value = option;
+                _$insert(_el$4, option);
+                return _el$4;
+            })()));
+    _el$3.value = initial;
+    return _el$3;
+})();
+const staticOptions = (()=>{
+    const _el$5 = _tmpl$3();
+    _$effect(()=>_el$5.value = selected());
+    return _el$5;
+})();
+const bound = (()=>{
+    const _el$6 = _tmpl$4(), _el$7 = _el$6.firstChild;
+    _el$6.addEventListener("change", (e)=>setChoice(e.currentTarget.value));
+    _$insert(_el$7, /*#__PURE__*/ _$createComponent(_$For, {
+        get each () {
+            return choices();
+        },
+        children: (choice1)=>(()=>{
+                const _el$8 = _tmpl$2();
+                _$insert(_el$8, choice1);
+                return _el$8;
+            })()
+    }));
+    _$effect(()=>_el$6.value = choice());
+    return _el$6;
+})();
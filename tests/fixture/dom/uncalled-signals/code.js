@@ -0,0 +1,29 @@
+import { createSignal, createMemo } from "r-dom";
+import { createSignal as createStore } from "./store";
+
+const [count, setCount] = createSignal(0);
+const double = createMemo(() => count() * 2);
+const increment = () => setCount(count() + 1);
+const [items] = createStore([]);
+const [first] = [() => 1];
+function Counter(props) {
+  return <span>{props.value}</span>;
+}
+function renderRow(row) {
+  return <li>{row}</li>;
+}
+
+const template = (
+  <div title={count} ref={setCount} use:tooltip={count} onClick={increment}>
+    {count}
+    {count()}
+    {items}
+    {first}
+    <button on:click={increment} disabled={double}>
+      {double()}
+    </button>
+    <Counter value={count} onChange={setCount} fallback={Counter} />
+    <Counter>{double}</Counter>
+    <Counter>{renderRow}</Counter>
+  </div>
+);
@@ -0,0 +1,59 @@
+import { use as _$use } from "r-dom";
+import { template as _$template } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<span>`), _tmpl$2 = /*#__PURE__*/ _$template(`<li>`), _tmpl$3 = /*#__PURE__*/ _$template(`<div><button>`);
+import { createSignal, createMemo } from "r-dom";
+import { createSignal as createStore } from "./store";
+const [count, setCount] = createSignal(0);
+const double = createMemo(()=>count() * 2);
+const increment = ()=>setCount(count() + 1);
+const [items] = createStore([]);
+const [first] = [
+    ()=>1
+];
+function Counter(props) {
+    return (()=>{
+        const _el$ = _tmpl$();
+        _$insert(_el$, ()=>props.value);
+        return _el$;
+    })();
+}
+function renderRow(row) {
+    return (()=>{
+        const _el$2 = _tmpl$2();
+        _$insert(_el$2, row);
+        return _el$2;
+    })();
+}
+const template = (()=>{
+    const _el$3 = _tmpl$3(), _el$4 = _el$3.firstChild;
+    _el$3.$$click = increment;
+    _$use(tooltip, _el$3, ()=>count);
+    _$use(setCount, _el$3);
+    _$setAttribute(_el$3, "title", count);
+    _$insert(_el$3, count, _el$4);
+    _$insert(_el$3, count, _el$4);
+    _$insert(_el$3, items, _el$4);
+    _$insert(_el$3, first, _el$4);
+    _el$4.addEventListener("click", increment);
+    _el$4.disabled = double;
+    _$insert(_el$4, double);
+    _$insert(_el$3, /*#__PURE__*/ _$createComponent(Counter, {
+        value: count,
+        onChange: setCount,
+        fallback: Counter
+    }), null);
+    _$insert(_el$3, /*#__PURE__*/ _$createComponent(Counter, {
+        children: double
+    }), null);
+    _$insert(_el$3, /*#__PURE__*/ _$createComponent(Counter, {
+        children: renderRow
+    }), null);
+    return _el$3;
+})();
+_$delegateEvents([
+    "click"
+]);
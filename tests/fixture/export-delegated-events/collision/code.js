@@ -0,0 +1,3 @@
+import { delegatedEvents } from "./events";
+
+const template = <button onClick={() => console.log(delegatedEvents)} />;
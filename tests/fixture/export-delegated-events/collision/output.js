@@ -0,0 +1,8 @@
+import { template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<button>`);
+import { delegatedEvents } from "./events";
+const template = /*#__PURE__*/ (()=>{
+    const _el$ = _tmpl$();
+    _el$.$$click = ()=>console.log(delegatedEvents);
+    return _el$;
+})();
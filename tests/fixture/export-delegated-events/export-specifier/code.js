@@ -0,0 +1,5 @@
+const events = ["click"];
+
+const template = <button onClick={() => console.log(events)} />;
+
+export { events as delegatedEvents };
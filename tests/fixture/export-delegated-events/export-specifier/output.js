@@ -0,0 +1,11 @@
+import { template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<button>`);
+const events = [
+    "click"
+];
+const template = /*#__PURE__*/ (()=>{
+    const _el$ = _tmpl$();
+    _el$.$$click = ()=>console.log(events);
+    return _el$;
+})();
+export { events as delegatedEvents };
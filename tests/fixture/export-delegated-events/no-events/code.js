@@ -0,0 +1 @@
+const template = <div class="static" />;
@@ -0,0 +1,4 @@
+import { template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="static">`);
+const template = _tmpl$();
+export const delegatedEvents = [];
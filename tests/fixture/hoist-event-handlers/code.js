@@ -0,0 +1,54 @@
+import { track } from "./analytics";
+
+const selected = new Set();
+let count = 0;
+
+function select(id) {
+  selected.add(id);
+}
+
+function Row(props) {
+  const label = props.label;
+  return (
+    <li
+      onClick={() => track("cta")}
+      onDblClick={[select, props.id]}
+      onMouseDown={[(id, e) => select(id, e.button), props.id]}
+      onKeyDown={e => track(label, e.key)}
+      onFocus={function () {
+        count++;
+        this.classList.add("focused");
+      }}
+      on:pointerenter={e => {
+        const { target } = e;
+        console.log(target, count);
+      }}
+      onInput={() => console.log(props.label)}
+      onBlur={() => render(<Toast onClose={() => track("toast")} />)}
+    >
+      {props.label}
+    </li>
+  );
+}
+
+function Panel(props) {
+  const ui = props.components;
+  let open = false;
+  return (
+    <div
+      onClick={() => (open = !open)}
+      onFocus={() => {
+        let next;
+        next = count + 1;
+        console.log(next);
+      }}
+      onBlur={() => render(<ui.Panel />)}
+    />
+  );
+}
+
+class Widget {
+  render() {
+    return <div onClick={() => render(<this.Icon />)} />;
+  }
+}
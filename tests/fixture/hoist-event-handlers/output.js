@@ -0,0 +1,69 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<li>`), _tmpl$2 = /*#__PURE__*/ _$template(`<div>`);
+const _handler$ = ()=>track("cta"), _handler$2 = (id, e)=>select(id, e.button), _handler$3 = function() {
+    count++;
+    this.classList.add("focused");
+}, _handler$4 = (e)=>{
+    const { target } = e;
+    console.log(target, count);
+}, _handler$5 = ()=>render(/*#__PURE__*/ _$createComponent(Toast, {
+        onClose: ()=>track("toast")
+    })), _handler$6 = ()=>{
+    let next;
+    next = count + 1;
+    console.log(next);
+};
+import { track } from "./analytics";
+const selected = new Set();
+let count = 0;
+function select(id) {
+    selected.add(id);
+}
+function Row(props) {
+    const label = props.label;
+    return (()=>{
+        const _el$ = _tmpl$();
+        _el$.addEventListener("blur", _handler$5);
+        _el$.$$input = ()=>console.log(props.label);
+        _el$.addEventListener("focus", _handler$3);
+        _el$.$$keydown = (e)=>track(label, e.key);
+        _el$.$$mousedown = _handler$2;
+        _el$.$$mousedownData = props.id;
+        _el$.$$dblclick = select;
+        _el$.$$dblclickData = props.id;
+        _el$.$$click = _handler$;
+        _el$.addEventListener("pointerenter", _handler$4);
+        _$insert(_el$, ()=>props.label);
+        return _el$;
+    })();
+}
+function Panel(props) {
+    const ui = props.components;
+    let open = false;
+    return (()=>{
+        const _el$2 = _tmpl$2();
+        _el$2.addEventListener("blur", ()=>render(/*#__PURE__*/ _$createComponent(ui.Panel, {})));
+        _el$2.addEventListener("focus", _handler$6);
+        _el$2.$$click = ()=>open = !open;
+        return _el$2;
+    })();
+}
+class Widget {
+    render() {
+        return /*#__PURE__*/ (()=>{
+            const _el$3 = _tmpl$2();
+            _el$3.$$click = ()=>render(/*#__PURE__*/ _$createComponent(this.Icon, {}));
+            return _el$3;
+        })();
+    }
+}
+_$delegateEvents([
+    "click",
+    "dblclick",
+    "input",
+    "keydown",
+    "mousedown"
+]);
@@ -0,0 +1,52 @@
+import { For } from "r-dom";
+
+const jsx1 = (
+  <div id="main" class={state.class} onClick={select}>
+    <h1>Hello {name}!</h1>
+    <input type="text" value={value()} disabled />
+  </div>
+);
+const html1 = html`
+  <div id="main" class=${state.class} onClick=${select}>
+    <h1>Hello ${name}!</h1>
+    <input type="text" value=${value()} disabled>
+  </div>
+`;
+
+const jsx2 = (
+  <For each={list()}>{(item) => <li class={`item ${item.kind}`}>{item.label}</li>}</For>
+);
+const html2 = html`
+  <${For} each=${list()}>${(item) => html`<li class="item ${item.kind}">${item.label}</li>`}<//>
+`;
+
+const jsx3 = (
+  <Child {...props} name="static" ref={el} />
+);
+const html3 = html`<${Child} ...${props} name="static" ref=${el} />`;
+
+const jsx4 = (
+  <>
+    <span>First</span>
+    <span>Second</span>
+  </>
+);
+const html4 = html`
+  <span>First</span>
+  <!-- comment -->
+  <span>Second</span>
+`;
+
+const jsx5 = <svg:circle use:tooltip={tip} prop:value={value} />;
+const html5 = html`<svg:circle use:tooltip=${tip} prop:value=${value} />`;
+
+// a local `html` is left alone
+function render(html) {
+  return html`<div>${name}</div>`;
+}
+
+const malformed = html`<div><span></div>`;
+
+function Widget() {
+  return html`<${Comp} value=${this.x} />`;
+}
@@ -0,0 +1,99 @@
+import { use as _$use } from "r-dom";
+import { template as _$template } from "r-dom";
+import { mergeProps as _$mergeProps } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { className as _$className } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div id="main"><h1>Hello <!>!</h1><input type="text" disabled>`), _tmpl$2 = /*#__PURE__*/ _$template(`<li>`), _tmpl$3 = /*#__PURE__*/ _$template(`<span>First`), _tmpl$4 = /*#__PURE__*/ _$template(`<span>Second`), _tmpl$5 = /*#__PURE__*/ _$template(`<svg:circle>`);
+import { For } from "r-dom";
+const jsx1 = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.firstChild, _el$5 = _el$3.nextSibling, _el$4 = _el$5.nextSibling, _el$6 = _el$2.nextSibling;
+    _$addEventListener(_el$, "click", select, true);
+    _$insert(_el$2, name, _el$5);
+    _$effect(()=>_$className(_el$, state.class));
+    _$effect(()=>_el$6.value = value());
+    return _el$;
+})();
+const html1 = (()=>{
+    const _el$7 = _tmpl$(), _el$8 = _el$7.firstChild, _el$9 = _el$8.firstChild, _el$11 = _el$9.nextSibling, _el$10 = _el$11.nextSibling, _el$12 = _el$8.nextSibling;
+    _$addEventListener(_el$7, "click", select, true);
+    _$insert(_el$8, name, _el$11);
+    _$effect(()=>_$className(_el$7, state.class));
+    _$effect(()=>_el$12.value = value());
+    return _el$7;
+})();
+const jsx2 = /*#__PURE__*/ _$createComponent(For, {
+    get each () {
+        return list();
+    },
+    children: (item)=>(()=>{
+            const _el$13 = _tmpl$2();
+            _$insert(_el$13, ()=>item.label);
+            _$effect(()=>_$className(_el$13, `item ${item.kind}`));
+            return _el$13;
+        })()
+});
+const html2 = /*#__PURE__*/ _$createComponent(For, {
+    get each () {
+        return list();
+    },
+    children: (item)=>(()=>{
+            const _el$14 = _tmpl$2();
+            _$insert(_el$14, ()=>item.label);
+            _$effect(()=>_$className(_el$14, `item ${item.kind}`));
+            return _el$14;
+        })()
+});
+const jsx3 = /*#__PURE__*/ _$createComponent(Child, _$mergeProps(props, {
+    name: "static",
+    ref (r$) {
+        const _ref$ = el;
+        typeof _ref$ === "function" ? _ref$(r$) : el = r$;
+    }
+}));
+const html3 = /*#__PURE__*/ _$createComponent(Child, _$mergeProps(props, {
+    name: "static",
+    ref (r$) {
+        const _ref$2 = el;
+        typeof _ref$2 === "function" ? _ref$2(r$) : el = r$;
+    }
+}));
+const jsx4 = [
+    _tmpl$3(),
+    _tmpl$4()
+];
+const html4 = [
+    _tmpl$3(),
+    _tmpl$4()
+];
+const jsx5 = (()=>{
+    const _el$19 = _tmpl$5();
+    _$use(tooltip, _el$19, ()=>tip);
+    _el$19.value = value;
+    return _el$19;
+})();
+const html5 = (()=>{
+    const _el$20 = _tmpl$5();
+    _$use(tooltip, _el$20, ()=>tip);
+    _el$20.value = value;
+    return _el$20;
+})();
+// a local `html` is left alone
+function render(html1) {
+    return html1`<div>${name}</div>`;
+}
+const malformed = html`<div><span></div>`;
+function Widget() {
+    const _self$ = this;
+    return /*#__PURE__*/ _$createComponent(Comp, {
+        get value () {
+            return _self$.x;
+        }
+    });
+}
+_$delegateEvents([
+    "click"
+]);
@@ -0,0 +1,14 @@
+const header = <header class="page-header">Title</header>;
+
+const list = (
+  <ul>
+    <li>{first()}</li>
+    <li>{second()}</li>
+  </ul>
+);
+
+const icon = (
+  <svg viewBox="0 0 24 24">
+    <path d="M2 12h20" />
+  </svg>
+);
@@ -0,0 +1,20 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ (()=>{
+    let t;
+    return ()=>(t || (t = _$template(`<header class="page-header">Title`)))();
+})(), _tmpl$2 = /*#__PURE__*/ (()=>{
+    let t;
+    return ()=>(t || (t = _$template(`<ul><li></li><li>`)))();
+})(), _tmpl$3 = /*#__PURE__*/ (()=>{
+    let t;
+    return ()=>(t || (t = _$template(`<svg viewBox="0 0 24 24"><path d="M2 12h20">`)))();
+})();
+const header = _tmpl$();
+const list = (()=>{
+    const _el$2 = _tmpl$2(), _el$3 = _el$2.firstChild, _el$4 = _el$3.nextSibling;
+    _$insert(_el$3, first);
+    _$insert(_el$4, second);
+    return _el$2;
+})();
+const icon = _tmpl$3();
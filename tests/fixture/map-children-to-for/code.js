@@ -0,0 +1,34 @@
+const list = (
+  <ul>
+    {items().map((item) => (
+      <li class="row">{item.name}</li>
+    ))}
+  </ul>
+);
+
+const withBlock = (
+  <div>
+    <h1>Title</h1>
+    {props.rows.map(function (row) {
+      const label = format(row);
+      return <span>{label}</span>;
+    })}
+  </div>
+);
+
+const inComponent = <Table>{rows.map((row) => <Row row={row} />)}</Table>;
+
+// `<For>` passes the index as an accessor
+const withIndex = <ul>{items().map((item, i) => <li data-index={i}>{i + 1}</li>)}</ul>;
+const withShorthand = <ul>{items().map((item, i) => <li>{format({ item, i })}</li>)}</ul>;
+
+// the array itself is only available to `.map`
+const withArray = <ul>{items().map((item, i, all) => <li>{all.length}</li>)}</ul>;
+
+// not arrays
+const store = { map: (render) => render(state) };
+const custom = <div>{store.map((value) => <b>{value}</b>)}</div>;
+const inline = <div>{({ map: (render) => render(1) }).map((value) => <b>{value}</b>)}</div>;
+
+// not JSX rows
+const strings = <p>{names.map((name) => name.toUpperCase())}</p>;
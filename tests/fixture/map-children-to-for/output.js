@@ -0,0 +1,122 @@
+import { template as _$template } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { memo as _$memo } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { For as _$For } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<ul>`), _tmpl$2 = /*#__PURE__*/ _$template(`<li class="row">`), _tmpl$3 = /*#__PURE__*/ _$template(`<div><h1>Title`), _tmpl$4 = /*#__PURE__*/ _$template(`<span>`), _tmpl$5 = /*#__PURE__*/ _$template(`<li>`), _tmpl$6 = /*#__PURE__*/ _$template(`<div>`), _tmpl$7 = /*#__PURE__*/ _$template(`<b>`), _tmpl$8 = /*#__PURE__*/ _$template(`<p>`);
+const list = (()=>{
+    const _el$ = _tmpl$();
+    _$insert(_el$, /*#__PURE__*/ _$createComponent(_$For, {
+        get each () {
+            return items();
+        },
+        children: (item)=>(()=>{
+                const _el$2 = _tmpl$2();
+                _$insert(_el$2, ()=>item.name);
+                return _el$2;
+            })()
+    }));
+    return _el$;
+})();
+const withBlock = (()=>{
+    const _el$3 = _tmpl$3(), _el$4 = _el$3.firstChild;
+    _$insert(_el$3, /*#__PURE__*/ _$createComponent(_$For, {
+        get each () {
+            return props.rows;
+        },
+        children: function(row) {
+            const label = format(row);
+            return (()=>{
+                const _el$5 = _tmpl$4();
+                _$insert(_el$5, label);
+                return _el$5;
+            })();
+        }
+    }), null);
+    return _el$3;
+})();
+const inComponent = /*#__PURE__*/ _$createComponent(Table, {
+    get children () {
+        return /*#__PURE__*/ _$createComponent(_$For, {
+            each: rows,
+            children: (row)=>/*#__PURE__*/ _$createComponent(Row, {
+                    row: row
+                })
+        });
+    }
+});
+// `<For>` passes the index as an accessor
+const withIndex = (()=>{
+    const _el$6 = _tmpl$();
+    _$insert(_el$6, /*#__PURE__*/ _$createComponent(_$For, {
+        get each () {
+            return items();
+        },
+        children: (item, i)=>(()=>{
+                const _el$7 = _tmpl$5();
+                _$insert(_el$7, ()=>i() + 1);
+                _$effect(()=>_$setAttribute(_el$7, "data-index", i()));
+                return _el$7;
+            })()
+    }));
+    return _el$6;
+})();
+const withShorthand = (()=>{
+    const _el$8 = _tmpl$();
+    _$insert(_el$8, /*#__PURE__*/ _$createComponent(_$For, {
+        get each () {
+            return items();
+        },
+        children: (item, i)=>(()=>{
+                const _el$9 = _tmpl$5();
+                _$insert(_el$9, ()=>format({
+                        item,
+                        i: i()
+                    }));
+                return _el$9;
+            })()
+    }));
+    return _el$8;
+})();
+// the array itself is only available to `.map`
+const withArray = (()=>{
+    const _el$10 = _tmpl$();
+    _$insert(_el$10, ()=>items().map((item, i, all)=>(()=>{
+                const _el$11 = _tmpl$5();
+                _$insert(_el$11, ()=>all.length);
+                return _el$11;
+            })()));
+    return _el$10;
+})();
+// not arrays
+const store = {
+    map: (render)=>render(state)
+};
+const custom = (()=>{
+    const _el$12 = _tmpl$6();
+    _$insert(_el$12, ()=>store.map((value)=>(()=>{
+                const _el$13 = _tmpl$7();
+                _$insert(_el$13, value);
+                return _el$13;
+            })()));
+    return _el$12;
+})();
+const inline = (()=>{
+    const _el$14 = _tmpl$6();
+    _$insert(_el$14, ()=>({
+            map: (render)=>render(1)
+        }).map((value)=>(()=>{
+                const _el$15 = _tmpl$7();
+                _$insert(_el$15, value);
+                return _el$15;
+            })()));
+    return _el$14;
+})();
+// not JSX rows
+const strings = (()=>{
+    const _el$16 = _tmpl$8();
+    _$insert(_el$16, ()=>names.map((name)=>name.toUpperCase()));
+    return _el$16;
+})();
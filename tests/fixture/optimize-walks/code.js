@@ -0,0 +1,53 @@
+const deepLeaf = (
+  <div>
+    <section>
+      <article>
+        <p>
+          <span>{value()}</span>
+        </p>
+      </article>
+    </section>
+  </div>
+);
+
+const lastChild = (
+  <ul>
+    <li>One</li>
+    <li>Two</li>
+    <li>Three</li>
+    <li>Four</li>
+    <li class={active()}>Five</li>
+  </ul>
+);
+
+const branches = (
+  <div>
+    <header>
+      <h1>{title()}</h1>
+    </header>
+    <main>
+      <p>
+        <b>{first()}</b>
+      </p>
+      <p>
+        <b>{second()}</b>
+      </p>
+    </main>
+    <footer>Static</footer>
+  </div>
+);
+
+const textAndPlaceholders = (
+  <div>
+    Hello {name()}, you have {count()} messages
+    <input value={value()} />
+  </div>
+);
+
+const unreferencedTail = (
+  <div>
+    <span>{a()}</span>
+    <span />
+    <span />
+  </div>
+);
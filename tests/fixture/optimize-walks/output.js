@@ -0,0 +1,34 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { className as _$className } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div><section><article><p><span>`), _tmpl$2 = /*#__PURE__*/ _$template(`<ul><li>One</li><li>Two</li><li>Three</li><li>Four</li><li>Five`), _tmpl$3 = /*#__PURE__*/ _$template(`<div><header><h1></h1></header><main><p><b></b></p><p><b></b></p></main><footer>Static`), _tmpl$4 = /*#__PURE__*/ _$template(`<div>Hello <!>, you have <!> messages<input>`), _tmpl$5 = /*#__PURE__*/ _$template(`<div><span></span><span></span><span>`);
+const deepLeaf = (()=>{
+    const _el$ = _tmpl$(), _el$5 = _el$.firstChild.firstChild.firstChild.firstChild;
+    _$insert(_el$5, value);
+    return _el$;
+})();
+const lastChild = (()=>{
+    const _el$6 = _tmpl$2(), _el$11 = _el$6.lastChild;
+    _$effect(()=>_$className(_el$11, active()));
+    return _el$6;
+})();
+const branches = (()=>{
+    const _el$12 = _tmpl$3(), _el$14 = _el$12.firstChild.firstChild, _el$15 = _el$12.firstChild.nextSibling, _el$17 = _el$15.firstChild.firstChild, _el$19 = _el$15.lastChild.firstChild;
+    _$insert(_el$14, title);
+    _$insert(_el$17, first);
+    _$insert(_el$19, second);
+    return _el$12;
+})();
+const textAndPlaceholders = (()=>{
+    const _el$20 = _tmpl$4(), _el$25 = _el$20.firstChild.nextSibling, _el$26 = _el$25.nextSibling.nextSibling, _el$24 = _el$20.lastChild;
+    _$insert(_el$20, name, _el$25);
+    _$insert(_el$20, count, _el$26);
+    _$effect(()=>_el$24.value = value());
+    return _el$20;
+})();
+const unreferencedTail = (()=>{
+    const _el$27 = _tmpl$5(), _el$28 = _el$27.firstChild;
+    _$insert(_el$28, a);
+    return _el$27;
+})();
@@ -0,0 +1,25 @@
+const search = <input className="search" onChange={e => setQuery(e.target.value)} />;
+
+const checkbox = <input type="checkbox" onChange={toggle} defaultChecked />;
+
+const notes = <textarea defaultValue="Write here" onChange={update} />;
+
+const prefilled = <input defaultValue={initialName} />;
+
+const html = <div dangerouslySetInnerHTML={{ __html: markup }} />;
+
+const htmlObject = <div dangerouslySetInnerHTML={content} />;
+
+const styled = (
+  <div style={{ fontSize: 12, lineHeight: 1.5, WebkitTransition: "none", marginTop: 0, color }} />
+);
+
+const list = (
+  <ul>
+    {items.map(item => (
+      <li key={item.id}>
+        <Item key={item.id} item={item} />
+      </li>
+    ))}
+  </ul>
+);
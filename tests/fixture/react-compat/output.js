@@ -0,0 +1,57 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<input class="search">`), _tmpl$2 = /*#__PURE__*/ _$template(`<input type="checkbox" checked>`), _tmpl$3 = /*#__PURE__*/ _$template(`<textarea>`), _tmpl$4 = /*#__PURE__*/ _$template(`<input>`), _tmpl$5 = /*#__PURE__*/ _$template(`<div>`), _tmpl$6 = /*#__PURE__*/ _$template(`<div style="font-size:12px;line-height:1.5;-webkit-transition:none;margin-top:0">`), _tmpl$7 = /*#__PURE__*/ _$template(`<ul>`), _tmpl$8 = /*#__PURE__*/ _$template(`<li>`);
+const search = /*#__PURE__*/ (()=>{
+    const _el$ = _tmpl$();
+    _el$.$$input = (e)=>setQuery(e.target.value);
+    return _el$;
+})();
+const checkbox = (()=>{
+    const _el$2 = _tmpl$2();
+    _$addEventListener(_el$2, "change", toggle);
+    return _el$2;
+})();
+const notes = (()=>{
+    const _el$3 = _tmpl$3();
+    _$addEventListener(_el$3, "input", update, true);
+    _el$3.defaultValue = "Write here";
+    return _el$3;
+})();
+const prefilled = /*#__PURE__*/ (()=>{
+    const _el$4 = _tmpl$4();
+    _el$4.defaultValue = initialName;
+    return _el$4;
+})();
+const html = /*#__PURE__*/ (()=>{
+    const _el$5 = _tmpl$5();
+    _el$5.innerHTML = markup;
+    return _el$5;
+})();
+const htmlObject = (()=>{
+    const _el$6 = _tmpl$5();
+    _$effect(()=>_el$6.innerHTML = content.__html);
+    return _el$6;
+})();
+const styled = (()=>{
+    const _el$7 = _tmpl$6();
+    color != null ? _el$7.style.setProperty("color", color) : _el$7.style.removeProperty("color");
+    return _el$7;
+})();
+const list = (()=>{
+    const _el$8 = _tmpl$7();
+    _$insert(_el$8, ()=>items.map((item)=>(()=>{
+                const _el$9 = _tmpl$8();
+                _$insert(_el$9, /*#__PURE__*/ _$createComponent(Item, {
+                    item: item
+                }));
+                return _el$9;
+            })()));
+    return _el$8;
+})();
+_$delegateEvents([
+    "input"
+]);
@@ -0,0 +1,19 @@
+import { template as _$template } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div> <span>Hello</span> <span></span> `), _tmpl$2 = /*#__PURE__*/ _$template(`<b>bold`), _tmpl$3 = /*#__PURE__*/ _$template(`<i>italic`);
+const template = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling, _el$4 = _el$3.nextSibling, _el$5 = _el$4.nextSibling;
+    _$insert(_el$5, name);
+    return _el$;
+})();
+const fragment = [
+    " ",
+    _tmpl$2(),
+    " ",
+    _tmpl$3(),
+    " "
+];
+const component = /*#__PURE__*/ _$createComponent(Comp, {
+    children: " Some text "
+});
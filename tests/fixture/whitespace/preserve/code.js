@@ -0,0 +1,27 @@
+const template = (
+  <div>
+    <span>Hello</span>
+    <span>{name}</span>
+  </div>
+);
+
+const fragment = (
+  <>
+    <b>bold</b>   <i>italic</i>
+  </>
+);
+
+const component = (
+  <Comp>
+    Some
+      text
+  </Comp>
+);
+
+const blankLines = (
+  <Comp>
+    first
+
+    second &amp; third
+  </Comp>
+);